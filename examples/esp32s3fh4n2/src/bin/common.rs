@@ -13,11 +13,10 @@ use esp_println::println;
 esp_bootloader_esp_idf::esp_app_desc!();
 
 use tps55288::data_types::{
-    CableCompLevel, CableCompOption, FaultStatus, FeedbackSource, InternalFeedbackRatio, OcpDelay,
-    OperatingStatus, VoutSlewRate,
+    CableCompLevel, CableCompOption, FeedbackSource, InternalFeedbackRatio, OcpDelay, VoutSlewRate,
 };
 use tps55288::driver::Tps55288;
-use tps55288::registers::{addr, ModeBits};
+use tps55288::registers::ModeBits;
 
 // Pin mapping (ESP32-S3 QFN56):
 // - pin 42 = GPIO37 (wired to CE through an inverter)
@@ -92,12 +91,11 @@ where
 
     // Force FPWM at light load using MODE register:
     // MODE bit0 = 1 -> override resistor preset, PFM bit1 = 1 -> FPWM (per datasheet).
-    match dev.read_reg(addr::MODE) {
-        Ok(raw) => {
-            let mut mode = ModeBits::from_bits_truncate(raw);
+    match dev.read_mode() {
+        Ok(mut mode) => {
             mode.insert(ModeBits::MODE);
             mode.insert(ModeBits::PFM);
-            if let Err(e) = dev.write_reg(addr::MODE, mode.bits()) {
+            if let Err(e) = dev.write_mode(mode) {
                 println!("set FPWM failed: {:?}", e);
             }
         }
@@ -110,76 +108,15 @@ where
     }
 }
 
-pub fn log_status(mv: u16, mode: OperatingStatus, faults: FaultStatus) {
-    if faults.short_circuit || faults.over_current || faults.over_voltage {
-        println!(
-            "WARN vset={}mV mode={:?} sc:{} oc:{} ov:{}",
-            mv, mode, faults.short_circuit, faults.over_current, faults.over_voltage
-        );
-    } else {
-        println!(
-            "vset={}mV mode={:?} sc:{} oc:{} ov:{}",
-            mv, mode, faults.short_circuit, faults.over_current, faults.over_voltage
-        );
-    }
-}
-
-pub fn log_mode_register(mode: ModeBits) {
-    let oe = mode.contains(ModeBits::OE);
-    let fsw2x = mode.contains(ModeBits::FSWDBL);
-    let hiccup = mode.contains(ModeBits::HICCUP);
-    let dischg = mode.contains(ModeBits::DISCHG);
-    let vcc_ext = mode.contains(ModeBits::VCC_EXT);
-    let i2c_alt = mode.contains(ModeBits::I2CADD);
-    let override_from_reg = mode.contains(ModeBits::MODE);
-    let pfm_bit = mode.contains(ModeBits::PFM);
-
-    // Per datasheet: PFM bit=0 => PFM, PFM bit=1 => FPWM.
-    let light_load = if override_from_reg {
-        if pfm_bit {
-            "forced FPWM"
-        } else {
-            "forced PFM"
-        }
-    } else if pfm_bit {
-        "FPWM (from preset)"
-    } else {
-        "PFM (from preset)"
-    };
-
-    let vcc = if vcc_ext {
-        "VCC=external 5V"
-    } else {
-        "VCC=internal LDO"
-    };
-
-    let addr = if i2c_alt { "I2C addr=0x75" } else { "I2C addr=0x74" };
-
-    println!(
-        "MODE=0x{:02X} oe:{} fsw:{} hiccup:{} dischg:{} {} {} light_load={}",
-        mode.bits(),
-        oe,
-        if fsw2x { "2x" } else { "1x" },
-        hiccup,
-        dischg,
-        vcc,
-        addr,
-        light_load
-    );
-}
-
+/// Log the STATUS/MODE summary for the current setpoint.
 pub fn log_status_and_mode<I2C>(dev: &mut Tps55288<I2C>, mv: u16)
 where
     I2C: embedded_hal::i2c::I2c,
 {
-    if let Ok((mode, faults)) = dev.read_status() {
-        log_status(mv, mode, faults);
-    }
-    if let Ok(raw_mode) = dev.read_reg(addr::MODE) {
-        let mode = ModeBits::from_bits_truncate(raw_mode);
-        log_mode_register(mode);
-    } else {
-        println!("read MODE failed in status loop");
+    match dev.read_report() {
+        Ok(report) if report.faults.any() => println!("WARN vset={}mV {}", mv, report),
+        Ok(report) => println!("vset={}mV {}", mv, report),
+        Err(_) => println!("read MODE/STATUS failed in status loop"),
     }
 }
 
@@ -4,11 +4,9 @@
 use esp_hal::main;
 use esp_println::println;
 
-use tps55288::data_types::{
-    CableCompLevel, CableCompOption, FeedbackSource, InternalFeedbackRatio, OcpDelay, VoutSlewRate,
-};
+use tps55288::data_types::{CableCompLevel, CableCompOption, OcpDelay, VoutSlewRate};
 use tps55288::driver::Tps55288;
-use tps55288::registers::{addr, ModeBits};
+use tps55288::registers::ModeBits;
 
 use esp_backtrace as _;
 
@@ -32,8 +30,8 @@ fn main() -> ! {
     }
 
     // Switch to *external* feedback network on FB/INT (SW2303 + resistor divider).
-    // INTFB ratio bits are ignored in external mode; keep the reset value (0.0564).
-    if let Err(e) = dev.set_feedback(FeedbackSource::External, InternalFeedbackRatio::R0_0564) {
+    // INTFB ratio bits are ignored in external mode and written as 0.
+    if let Err(e) = dev.set_external_feedback() {
         println!("set_feedback failed: {:?}", e);
     }
 
@@ -54,19 +52,17 @@ fn main() -> ! {
     //   VOUT ≈ VREF * (1 + Rtop/Rbottom) ≈ 1.2 V * 4.1646 ≈ 5.0 V
     // This makes the default external-FB output ≈5 V.
     let ref_code: u16 = 0x03FF; // 10-bit full-scale
-    let ref_bytes = ref_code.to_le_bytes();
-    if let Err(e) = dev.write_regs(addr::REF0, &ref_bytes) {
+    if let Err(e) = dev.set_vout_code_unchecked(ref_code) {
         println!("set REF (1.2V) failed: {:?}", e);
     }
 
     // Finally, enable the output (OE bit in MODE register) and force FPWM at light load.
     // In external FB mode, SW2303 + the resistor network define VOUT for a given REF code.
-    if let Ok(raw) = dev.read_reg(addr::MODE) {
-        let mut mode = ModeBits::from_bits_truncate(raw);
+    if let Ok(mut mode) = dev.read_mode() {
         mode.insert(ModeBits::MODE);
         mode.insert(ModeBits::PFM);
         mode.insert(ModeBits::OE);
-        if let Err(e) = dev.write_reg(addr::MODE, mode.bits()) {
+        if let Err(e) = dev.write_mode(mode) {
             println!("enable OE failed: {:?}", e);
         }
     } else {
@@ -8,11 +8,11 @@ use embassy_stm32::{
 use embassy_time::{Duration, Timer};
 
 use tps55288::data_types::{
-    CableCompLevel, CableCompOption, FeedbackSource, FaultStatus, InternalFeedbackRatio,
-    OcpDelay, OperatingStatus, VoutSlewRate,
+    CableCompLevel, CableCompOption, FeedbackSource, InternalFeedbackRatio, OcpDelay,
+    VoutSlewRate,
 };
 use tps55288::driver::Tps55288;
-use tps55288::registers::{addr, ModeBits};
+use tps55288::registers::ModeBits;
 
 /// Concrete I2C type for I2C1 on PB6/PB7 using DMA1 channels (async mode).
 pub type BoardI2c = I2c<'static, Async>;
@@ -85,11 +85,10 @@ where
 
     // Force FPWM at light load using MODE register:
     // MODE bit0 = 1 -> override resistor preset, PFM bit1 = 1 -> FPWM (per datasheet).
-    if let Ok(raw) = dev.read_reg(addr::MODE).await {
-        let mut mode = ModeBits::from_bits_truncate(raw);
+    if let Ok(mut mode) = dev.read_mode().await {
         mode.insert(ModeBits::MODE);
         mode.insert(ModeBits::PFM);
-        if let Err(e) = dev.write_reg(addr::MODE, mode.bits()).await {
+        if let Err(e) = dev.write_mode(mode).await {
             warn!("set FPWM failed: {:?}", defmt::Debug2Format(&e));
         }
     } else {
@@ -102,78 +101,15 @@ where
     }
 }
 
-pub fn log_status(mv: u16, mode: OperatingStatus, faults: FaultStatus) {
-    if faults.short_circuit || faults.over_current || faults.over_voltage {
-        warn!(
-            "vset={}mV mode={:?} sc:{} oc:{} ov:{}",
-            mv, mode, faults.short_circuit, faults.over_current, faults.over_voltage
-        );
-    } else {
-        info!(
-            "vset={}mV mode={:?} sc:{} oc:{} ov:{}",
-            mv, mode, faults.short_circuit, faults.over_current, faults.over_voltage
-        );
-    }
-}
-
-/// Decode MODE register into a human-readable summary.
-pub fn log_mode_register(mode: ModeBits) {
-    let oe = mode.contains(ModeBits::OE);
-    let fsw2x = mode.contains(ModeBits::FSWDBL);
-    let hiccup = mode.contains(ModeBits::HICCUP);
-    let dischg = mode.contains(ModeBits::DISCHG);
-    let vcc_ext = mode.contains(ModeBits::VCC_EXT);
-    let i2c_alt = mode.contains(ModeBits::I2CADD);
-    let override_from_reg = mode.contains(ModeBits::MODE);
-    let pfm_bit = mode.contains(ModeBits::PFM);
-
-    // Per datasheet: PFM bit=0 => PFM, PFM bit=1 => FPWM.
-    let light_load = if override_from_reg {
-        if pfm_bit {
-            "forced FPWM"
-        } else {
-            "forced PFM"
-        }
-    } else if pfm_bit {
-        "FPWM (from preset)"
-    } else {
-        "PFM (from preset)"
-    };
-
-    let vcc = if vcc_ext {
-        "VCC=external 5V"
-    } else {
-        "VCC=internal LDO"
-    };
-
-    let addr = if i2c_alt { "I2C addr=0x75" } else { "I2C addr=0x74" };
-
-    info!(
-        "MODE=0x{:02X} oe:{} fsw:{} hiccup:{} dischg:{} {} {} light_load={}",
-        mode.bits(),
-        oe,
-        if fsw2x { "2x" } else { "1x" },
-        hiccup,
-        dischg,
-        vcc,
-        addr,
-        light_load
-    );
-}
-
-/// Log STATUS and MODE registers in one shot (async I2C).
+/// Log the STATUS/MODE summary for the current setpoint (async I2C).
 pub async fn log_status_and_mode<I2C>(dev: &mut Tps55288<I2C>, mv: u16)
 where
     I2C: embedded_hal_async::i2c::I2c,
 {
-    if let Ok((mode, faults)) = dev.read_status().await {
-        log_status(mv, mode, faults);
-    }
-    if let Ok(raw_mode) = dev.read_reg(addr::MODE).await {
-        let mode = ModeBits::from_bits_truncate(raw_mode);
-        log_mode_register(mode);
-    } else {
-        warn!("read MODE failed in status loop");
+    match dev.read_report().await {
+        Ok(report) if report.faults.any() => warn!("vset={}mV {}", mv, report),
+        Ok(report) => info!("vset={}mV {}", mv, report),
+        Err(_) => warn!("read MODE/STATUS failed in status loop"),
     }
 }
 
@@ -9,12 +9,9 @@ use {defmt_rtt as _, panic_probe as _};
 
 mod common;
 use common::{init_board, log_status_and_mode};
-use tps55288::data_types::{
-    CableCompLevel, CableCompOption, FeedbackSource, InternalFeedbackRatio, OcpDelay,
-    VoutSlewRate,
-};
+use tps55288::data_types::{CableCompLevel, CableCompOption, OcpDelay, VoutSlewRate};
 use tps55288::driver::Tps55288;
-use tps55288::registers::{addr, ModeBits};
+use tps55288::registers::ModeBits;
 
 bind_interrupts!(struct Irqs {
     I2C1 => i2c::EventInterruptHandler<embassy_stm32::peripherals::I2C1>,
@@ -36,11 +33,8 @@ async fn main(_spawner: Spawner) {
     }
 
     // Switch to *external* feedback network on FB/INT (SW2303 + resistor divider).
-    // INTFB ratio bits are ignored in external mode; keep the reset value (0.0564).
-    if let Err(e) = dev
-        .set_feedback(FeedbackSource::External, InternalFeedbackRatio::R0_0564)
-        .await
-    {
+    // INTFB ratio bits are ignored in external mode and written as 0.
+    if let Err(e) = dev.set_external_feedback().await {
         defmt::warn!("set_feedback failed: {:?}", defmt::Debug2Format(&e));
     }
 
@@ -65,21 +59,19 @@ async fn main(_spawner: Spawner) {
     //   VOUT ≈ VREF * (1 + Rtop/Rbottom) ≈ 1.2 V * 4.1646 ≈ 5.0 V
     // This makes the default external-FB output ≈5 V.
     let ref_code: u16 = 0x03FF; // 10-bit full-scale
-    let ref_bytes = ref_code.to_le_bytes();
-    if let Err(e) = dev.write_regs(addr::REF0, &ref_bytes).await {
+    if let Err(e) = dev.set_vout_code_unchecked(ref_code).await {
         defmt::warn!("set REF (1.2V) failed: {:?}", defmt::Debug2Format(&e));
     }
 
     // Finally, enable the output (OE bit in MODE register) and force FPWM at light load.
     // In external FB mode, SW2303 + the resistor network define VOUT for a given REF code.
-    if let Ok(raw) = dev.read_reg(addr::MODE).await {
-        let mut mode = ModeBits::from_bits_truncate(raw);
+    if let Ok(mut mode) = dev.read_mode().await {
         // Force FPWM using MODE register: MODE=1 -> override preset, PFM=1 -> FPWM (per datasheet).
         mode.insert(ModeBits::MODE);
         mode.insert(ModeBits::PFM);
         // Enable output while keeping other bits from the MODE pin preset.
         mode.insert(ModeBits::OE);
-        if let Err(e) = dev.write_reg(addr::MODE, mode.bits()).await {
+        if let Err(e) = dev.write_mode(mode).await {
             defmt::warn!("enable OE failed: {:?}", defmt::Debug2Format(&e));
         }
     } else {
@@ -0,0 +1,143 @@
+//! Whole-device configuration applied by `Tps55288::init_with_config`.
+
+use crate::data_types::{
+    CableCompLevel, CableCompOption, FeedbackSource, InternalFeedbackRatio, LightLoadMode,
+    OcpDelay, VoutSlewRate,
+};
+
+/// Device configuration with a fluent builder.
+///
+/// `Config::default()` mirrors the datasheet reset state (5 V on the 0.0564 ratio, 5 A limit
+/// with a 10 mΩ sense resistor, 2.5 mV/µs, all fault indications enabled) with OE left off.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Target output voltage (mV), mapped through `feedback_ratio`.
+    pub vout_mv: u16,
+    /// Output current limit (mA; 50 mA LSB with the default 10 mΩ sense resistor).
+    pub ilim_ma: u16,
+    /// Current limit enable (IOUT_LIMIT bit7).
+    pub ilim_enabled: bool,
+    /// Internal vs external feedback divider.
+    pub feedback_source: FeedbackSource,
+    /// Internal feedback ratio (ignored by the chip in external mode).
+    pub feedback_ratio: InternalFeedbackRatio,
+    /// VOUT slew rate.
+    pub slew_rate: VoutSlewRate,
+    /// Overcurrent response delay.
+    pub ocp_delay: OcpDelay,
+    /// Cable droop compensation approach.
+    pub cable_comp_option: CableCompOption,
+    /// Cable droop compensation level.
+    pub cable_comp_level: CableCompLevel,
+    /// CDC SC_MASK bit (1 = short-circuit indication enabled).
+    pub mask_sc: bool,
+    /// CDC OCP_MASK bit (1 = overcurrent indication enabled).
+    pub mask_ocp: bool,
+    /// CDC OVP_MASK bit (1 = overvoltage indication enabled).
+    pub mask_ovp: bool,
+    /// Light-load mode forced through the MODE register, or `None` to keep the MODE-pin preset.
+    ///
+    /// Forcing it switches MODE bit0 to register control, which also applies the VCC and
+    /// I2CADD register bits.
+    pub light_load_mode: Option<LightLoadMode>,
+    /// Set OE once everything else has been written.
+    pub enable_output: bool,
+}
+
+impl Config {
+    /// Start from the datasheet reset configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the target output voltage (mV).
+    #[must_use]
+    pub fn vout_mv(mut self, mv: u16) -> Self {
+        self.vout_mv = mv;
+        self
+    }
+
+    /// Set the output current limit (mA) and its enable bit.
+    #[must_use]
+    pub fn ilim_ma(mut self, ma: u16, enable: bool) -> Self {
+        self.ilim_ma = ma;
+        self.ilim_enabled = enable;
+        self
+    }
+
+    /// Select the feedback source and internal ratio.
+    #[must_use]
+    pub fn feedback(mut self, source: FeedbackSource, ratio: InternalFeedbackRatio) -> Self {
+        self.feedback_source = source;
+        self.feedback_ratio = ratio;
+        self
+    }
+
+    /// Set the VOUT slew rate.
+    #[must_use]
+    pub fn slew_rate(mut self, slew: VoutSlewRate) -> Self {
+        self.slew_rate = slew;
+        self
+    }
+
+    /// Set the overcurrent response delay.
+    #[must_use]
+    pub fn ocp_delay(mut self, delay: OcpDelay) -> Self {
+        self.ocp_delay = delay;
+        self
+    }
+
+    /// Set cable droop compensation approach and level.
+    #[must_use]
+    pub fn cable_comp(mut self, option: CableCompOption, level: CableCompLevel) -> Self {
+        self.cable_comp_option = option;
+        self.cable_comp_level = level;
+        self
+    }
+
+    /// Set the CDC fault indication mask bits.
+    #[must_use]
+    pub fn fault_masks(mut self, mask_sc: bool, mask_ocp: bool, mask_ovp: bool) -> Self {
+        self.mask_sc = mask_sc;
+        self.mask_ocp = mask_ocp;
+        self.mask_ovp = mask_ovp;
+        self
+    }
+
+    /// Force the light-load mode via the MODE register.
+    #[must_use]
+    pub fn light_load_mode(mut self, mode: LightLoadMode) -> Self {
+        self.light_load_mode = Some(mode);
+        self
+    }
+
+    /// Enable the output as the final configuration step.
+    #[must_use]
+    pub fn enable_output(mut self, enable: bool) -> Self {
+        self.enable_output = enable;
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            vout_mv: 5_000,
+            ilim_ma: 5_000,
+            ilim_enabled: true,
+            feedback_source: FeedbackSource::Internal,
+            feedback_ratio: InternalFeedbackRatio::R0_0564,
+            slew_rate: VoutSlewRate::Sr2p5MvPerUs,
+            ocp_delay: OcpDelay::Us128,
+            cable_comp_option: CableCompOption::Internal,
+            cable_comp_level: CableCompLevel::V0p0,
+            mask_sc: true,
+            mask_ocp: true,
+            mask_ovp: true,
+            light_load_mode: None,
+            enable_output: false,
+        }
+    }
+}
@@ -1,16 +1,33 @@
 //! Data types for TPS55288 driver (skeleton, based on datasheet).
 //! Concrete value mappings will be filled when register bitfields are implemented.
 
+use crate::registers::{
+    ALT_I2C_ADDRESS, DEFAULT_I2C_ADDRESS, ILIM_MAX_CODE, IoutLimitBits, ModeBits, REF_CODE_MAX,
+    StatusBits, code_to_ilim_ma, decode_status,
+};
+
 /// I2C slave addresses available via MODE pin presets.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum I2cAddress {
     Addr0x74,
     Addr0x75,
 }
 
+impl I2cAddress {
+    /// 7-bit bus address.
+    pub fn addr(self) -> u8 {
+        match self {
+            I2cAddress::Addr0x74 => DEFAULT_I2C_ADDRESS,
+            I2cAddress::Addr0x75 => ALT_I2C_ADDRESS,
+        }
+    }
+}
+
 /// Light-load operating mode.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LightLoadMode {
     /// Pulse-frequency modulation at light load.
@@ -21,6 +38,7 @@ pub enum LightLoadMode {
 
 /// VCC source selection.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum VccSource {
     /// Internal LDO.
@@ -29,8 +47,19 @@ pub enum VccSource {
     External5v,
 }
 
+impl VccSource {
+    /// Label shared by `Display` and `StatusReport` ("internal LDO", "external 5 V").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VccSource::Internal => "internal LDO",
+            VccSource::External5v => "external 5 V",
+        }
+    }
+}
+
 /// Light-load operating mode selection (PFM/PWM).
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LightLoadOverride {
     /// Follow external resistor preset (MODE bit0 = 0).
@@ -41,6 +70,7 @@ pub enum LightLoadOverride {
 
 /// Output slew rate options for VOUT changes.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum VoutSlewRate {
     Sr1p25MvPerUs,
@@ -51,6 +81,7 @@ pub enum VoutSlewRate {
 
 /// Overcurrent response delay selections.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum OcpDelay {
     Us128,
@@ -61,6 +92,7 @@ pub enum OcpDelay {
 
 /// Feedback source selection.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FeedbackSource {
     Internal,
@@ -69,6 +101,7 @@ pub enum FeedbackSource {
 
 /// Internal feedback ratios (per datasheet INTFB bits).
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum InternalFeedbackRatio {
     R0_2256,
@@ -79,6 +112,7 @@ pub enum InternalFeedbackRatio {
 
 /// Cable droop compensation mode.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CableCompOption {
     Internal,
@@ -87,6 +121,7 @@ pub enum CableCompOption {
 
 /// Cable droop compensation level (CDC[2:0]).
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CableCompLevel {
     V0p0,
@@ -99,7 +134,127 @@ pub enum CableCompLevel {
     V0p7,
 }
 
+/// CDC readback `(option, level, mask_sc, mask_ocp, mask_ovp)`, in `set_cable_comp` argument order.
+pub type CableCompSettings = (CableCompOption, CableCompLevel, bool, bool, bool);
+
+/// CDC fault indication enables (SC_MASK/OCP_MASK/OVP_MASK).
+///
+/// These are the only masking controls on the chip; there is no separate interrupt enable.
+/// A set bit lets the matching STATUS fault pull FB/INT low (internal feedback only); a clear
+/// bit hides it from the pin. Protection itself is never disabled. `Default` is the reset
+/// state, all indications enabled.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FaultMaskConfig {
+    /// SC_MASK (bit7): indicate short circuit.
+    pub short_circuit: bool,
+    /// OCP_MASK (bit6): indicate overcurrent.
+    pub over_current: bool,
+    /// OVP_MASK (bit5): indicate overvoltage.
+    pub over_voltage: bool,
+}
+
+impl Default for FaultMaskConfig {
+    fn default() -> Self {
+        Self {
+            short_circuit: true,
+            over_current: true,
+            over_voltage: true,
+        }
+    }
+}
+
+/// Decoded MODE register (0x06).
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModeConfig {
+    /// OE (bit7).
+    pub output_enabled: bool,
+    /// FSWDBL (bit6): double switching frequency in buck-boost mode.
+    pub frequency_doubling: bool,
+    /// HICCUP (bit5): hiccup during output short-circuit protection.
+    pub hiccup: bool,
+    /// DISCHG (bit4): output discharge in shutdown.
+    pub discharge: bool,
+    /// VCC (bit3); only applied when `light_load_override` is `FromRegister`.
+    pub vcc_source: VccSource,
+    /// I2CADD (bit2); only applied when `light_load_override` is `FromRegister`.
+    pub i2c_address_select: I2cAddress,
+    /// MODE (bit0): whether VCC/I2CADD/PFM come from the MODE pin or this register.
+    pub light_load_override: LightLoadOverride,
+    /// PFM (bit1): 0 = PFM, 1 = FPWM; only applied when `light_load_override` is `FromRegister`.
+    pub light_load_mode: LightLoadMode,
+}
+
+impl ModeConfig {
+    /// Decode MODE register bits.
+    pub fn from_bits(bits: ModeBits) -> Self {
+        Self {
+            output_enabled: bits.contains(ModeBits::OE),
+            frequency_doubling: bits.contains(ModeBits::FSWDBL),
+            hiccup: bits.contains(ModeBits::HICCUP),
+            discharge: bits.contains(ModeBits::DISCHG),
+            vcc_source: if bits.contains(ModeBits::VCC_EXT) {
+                VccSource::External5v
+            } else {
+                VccSource::Internal
+            },
+            i2c_address_select: if bits.contains(ModeBits::I2CADD) {
+                I2cAddress::Addr0x75
+            } else {
+                I2cAddress::Addr0x74
+            },
+            light_load_override: if bits.contains(ModeBits::MODE) {
+                LightLoadOverride::FromRegister
+            } else {
+                LightLoadOverride::FromPreset
+            },
+            light_load_mode: if bits.contains(ModeBits::PFM) {
+                LightLoadMode::Pwm
+            } else {
+                LightLoadMode::Pfm
+            },
+        }
+    }
+
+    /// Encode into MODE register bits.
+    pub fn to_bits(&self) -> ModeBits {
+        let mut bits = ModeBits::empty();
+        bits.set(ModeBits::OE, self.output_enabled);
+        bits.set(ModeBits::FSWDBL, self.frequency_doubling);
+        bits.set(ModeBits::HICCUP, self.hiccup);
+        bits.set(ModeBits::DISCHG, self.discharge);
+        bits.set(
+            ModeBits::VCC_EXT,
+            matches!(self.vcc_source, VccSource::External5v),
+        );
+        bits.set(
+            ModeBits::I2CADD,
+            matches!(self.i2c_address_select, I2cAddress::Addr0x75),
+        );
+        bits.set(
+            ModeBits::MODE,
+            matches!(self.light_load_override, LightLoadOverride::FromRegister),
+        );
+        bits.set(
+            ModeBits::PFM,
+            matches!(self.light_load_mode, LightLoadMode::Pwm),
+        );
+        bits
+    }
+}
+
+impl Default for ModeConfig {
+    /// Datasheet reset value (MODE = 0x20: hiccup enabled, everything else cleared).
+    fn default() -> Self {
+        Self::from_bits(ModeBits::HICCUP)
+    }
+}
+
 /// MODE pin resistor preset entry from datasheet table.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ModePreset {
     pub resistor_kohm: Option<f32>,
@@ -108,7 +263,7 @@ pub struct ModePreset {
     pub light_load_mode: LightLoadMode,
 }
 
-/// Known presets from datasheet (ohms in kΩ). Values are for documentation; not used programmatically yet.
+/// Known presets from datasheet (ohms in kΩ); `resistor_kohm: None` means the MODE pin is left open.
 pub const MODE_PRESETS: [ModePreset; 8] = [
     ModePreset {
         resistor_kohm: Some(0.0),
@@ -160,6 +315,47 @@ pub const MODE_PRESETS: [ModePreset; 8] = [
     },
 ];
 
+/// Find the MODE-pin preset whose datasheet resistor is nearest to `kohm`.
+///
+/// `tolerance` is relative (0.01 = 1 %); the 0 Ω entry only matches exactly 0. An infinite
+/// `kohm` selects the open-pin preset. Returns `None` when no resistor is within tolerance.
+pub fn preset_for_resistor(kohm: f32, tolerance: f32) -> Option<&'static ModePreset> {
+    if kohm.is_infinite() {
+        return MODE_PRESETS.iter().find(|p| p.resistor_kohm.is_none());
+    }
+    let mut best: Option<(&'static ModePreset, f32)> = None;
+    for preset in MODE_PRESETS.iter() {
+        let Some(nominal) = preset.resistor_kohm else {
+            continue;
+        };
+        let diff = if kohm > nominal {
+            kohm - nominal
+        } else {
+            nominal - kohm
+        };
+        if diff <= nominal * tolerance && best.is_none_or(|(_, d)| diff < d) {
+            best = Some((preset, diff));
+        }
+    }
+    best.map(|(preset, _)| preset)
+}
+
+/// 7-bit I2C address the device answers on at boot with this MODE-pin preset.
+pub fn address_for_preset(preset: &ModePreset) -> u8 {
+    preset.address.addr()
+}
+
+/// Index into `MODE_PRESETS` of the preset that boots with the given configuration.
+pub fn preset_index_for_config(
+    vcc: VccSource,
+    address: I2cAddress,
+    mode: LightLoadMode,
+) -> Option<usize> {
+    MODE_PRESETS
+        .iter()
+        .position(|p| p.vcc_source == vcc && p.address == address && p.light_load_mode == mode)
+}
+
 /// STATUS decoded operating mode.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -170,6 +366,55 @@ pub enum OperatingStatus {
     Reserved,
 }
 
+impl From<u8> for OperatingStatus {
+    /// Decode STATUS[1:0]; higher bits are ignored, so a raw STATUS byte can be passed as is.
+    ///
+    /// `TryFrom<u8>` is available through the standard blanket impl and never fails.
+    fn from(raw: u8) -> Self {
+        match raw & 0b11 {
+            0b00 => OperatingStatus::Boost,
+            0b01 => OperatingStatus::Buck,
+            0b10 => OperatingStatus::BuckBoost,
+            _ => OperatingStatus::Reserved,
+        }
+    }
+}
+
+impl OperatingStatus {
+    /// True in boost mode (STATUS[1:0] = 00b).
+    pub fn is_boost(&self) -> bool {
+        matches!(self, OperatingStatus::Boost)
+    }
+
+    /// True in buck mode (STATUS[1:0] = 01b).
+    pub fn is_buck(&self) -> bool {
+        matches!(self, OperatingStatus::Buck)
+    }
+
+    /// True in buck-boost mode (STATUS[1:0] = 10b).
+    pub fn is_buck_boost(&self) -> bool {
+        matches!(self, OperatingStatus::BuckBoost)
+    }
+
+    /// True for the reserved code 11b.
+    ///
+    /// The datasheet does not define it (it is also the STATUS reset value); treat it as the
+    /// converter not yet settled in a regulation region.
+    pub fn is_transitioning(&self) -> bool {
+        matches!(self, OperatingStatus::Reserved)
+    }
+
+    /// Variant name as a static string ("Boost", "Buck", "BuckBoost", "Reserved").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperatingStatus::Boost => "Boost",
+            OperatingStatus::Buck => "Buck",
+            OperatingStatus::BuckBoost => "BuckBoost",
+            OperatingStatus::Reserved => "Reserved",
+        }
+    }
+}
+
 /// Fault flags decoded from STATUS.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -179,26 +424,486 @@ pub struct FaultStatus {
     pub over_voltage: bool,
 }
 
+/// A single latched fault reported in STATUS.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fault {
+    ShortCircuit,
+    OverCurrent,
+    OverVoltage,
+}
+
+impl FaultStatus {
+    /// Decode the SCP/OCP/OVP flags from STATUS bits.
+    pub fn from_bits(bits: StatusBits) -> Self {
+        Self {
+            short_circuit: bits.contains(StatusBits::SCP),
+            over_current: bits.contains(StatusBits::OCP),
+            over_voltage: bits.contains(StatusBits::OVP),
+        }
+    }
+
+    /// True when at least one fault flag is set.
+    pub fn any(&self) -> bool {
+        self.short_circuit || self.over_current || self.over_voltage
+    }
+
+    /// True when no fault flag is set.
+    pub fn is_clear(&self) -> bool {
+        !self.any()
+    }
+
+    /// Iterate over the active faults in STATUS bit order (SCP, OCP, OVP).
+    pub fn active(&self) -> impl Iterator<Item = Fault> {
+        [
+            (self.short_circuit, Fault::ShortCircuit),
+            (self.over_current, Fault::OverCurrent),
+            (self.over_voltage, Fault::OverVoltage),
+        ]
+        .into_iter()
+        .filter_map(|(set, fault)| set.then_some(fault))
+    }
+
+    /// Compact label for logging: "OK" when clear, otherwise the STATUS bit names joined
+    /// with '+' in bit order, e.g. "SCP" or "OCP+OVP".
+    pub fn summary(&self) -> &'static str {
+        match (self.short_circuit, self.over_current, self.over_voltage) {
+            (false, false, false) => "OK",
+            (true, false, false) => "SCP",
+            (false, true, false) => "OCP",
+            (false, false, true) => "OVP",
+            (true, true, false) => "SCP+OCP",
+            (true, false, true) => "SCP+OVP",
+            (false, true, true) => "OCP+OVP",
+            (true, true, true) => "SCP+OCP+OVP",
+        }
+    }
+}
+
+/// One-line device summary built from MODE and STATUS, as returned by `Tps55288::read_report`.
+///
+/// `Display` and `defmt::Format` print the same line, with faults as `FaultStatus::summary`,
+/// VCC as `VccSource`'s `Display` and the address in `I2cAddress`'s `{:#04x}` form, e.g.
+/// `mode=Buck faults=OK oe=on light_load=FPWM(reg) vcc=internal LDO addr=0x74`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StatusReport {
+    /// Operating mode from STATUS[1:0].
+    pub operating: OperatingStatus,
+    /// Latched faults from STATUS.
+    pub faults: FaultStatus,
+    /// MODE OE bit.
+    pub output_enabled: bool,
+    /// MODE PFM bit.
+    pub light_load_mode: LightLoadMode,
+    /// Whether the PFM/VCC bits come from the register or the MODE-pin preset.
+    pub light_load_override: LightLoadOverride,
+    /// MODE VCC bit.
+    pub vcc_source: VccSource,
+    /// Bus address the driver talks to.
+    pub address: u8,
+}
+
+impl StatusReport {
+    /// Build a report from raw MODE and STATUS bytes and the bus address.
+    pub fn from_registers(mode: u8, status: u8, address: u8) -> Self {
+        let mode = ModeConfig::from_bits(ModeBits::from_bits_truncate(mode));
+        let status = StatusBits::from_bits_retain(status);
+        Self {
+            operating: decode_status(&status),
+            faults: FaultStatus::from_bits(status),
+            output_enabled: mode.output_enabled,
+            light_load_mode: mode.light_load_mode,
+            light_load_override: mode.light_load_override,
+            vcc_source: mode.vcc_source,
+            address,
+        }
+    }
+
+    fn light_load_label(&self) -> &'static str {
+        match (self.light_load_mode, self.light_load_override) {
+            (LightLoadMode::Pfm, LightLoadOverride::FromRegister) => "PFM(reg)",
+            (LightLoadMode::Pwm, LightLoadOverride::FromRegister) => "FPWM(reg)",
+            (LightLoadMode::Pfm, LightLoadOverride::FromPreset) => "PFM(pin)",
+            (LightLoadMode::Pwm, LightLoadOverride::FromPreset) => "FPWM(pin)",
+        }
+    }
+}
+
+impl core::fmt::Display for I2cAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#04x}", self.addr())
+    }
+}
+
+impl core::fmt::Display for LightLoadMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            LightLoadMode::Pfm => "PFM",
+            LightLoadMode::Pwm => "FPWM",
+        })
+    }
+}
+
+impl core::fmt::Display for VccSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::fmt::Display for LightLoadOverride {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            LightLoadOverride::FromPreset => "MODE pin",
+            LightLoadOverride::FromRegister => "MODE register",
+        })
+    }
+}
+
+impl core::fmt::Display for VoutSlewRate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            VoutSlewRate::Sr1p25MvPerUs => "1.25 mV/µs",
+            VoutSlewRate::Sr2p5MvPerUs => "2.5 mV/µs",
+            VoutSlewRate::Sr5MvPerUs => "5 mV/µs",
+            VoutSlewRate::Sr10MvPerUs => "10 mV/µs",
+        })
+    }
+}
+
+impl core::fmt::Display for OcpDelay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            OcpDelay::Us128 => "128 µs",
+            OcpDelay::Ms3_072 => "3.072 ms",
+            OcpDelay::Ms6_144 => "6.144 ms",
+            OcpDelay::Ms12_288 => "12.288 ms",
+        })
+    }
+}
+
+impl core::fmt::Display for FeedbackSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            FeedbackSource::Internal => "internal",
+            FeedbackSource::External => "external",
+        })
+    }
+}
+
+impl core::fmt::Display for InternalFeedbackRatio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            InternalFeedbackRatio::R0_2256 => "0.2256",
+            InternalFeedbackRatio::R0_1128 => "0.1128",
+            InternalFeedbackRatio::R0_0752 => "0.0752",
+            InternalFeedbackRatio::R0_0564 => "0.0564",
+        })
+    }
+}
+
+impl core::fmt::Display for CableCompOption {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            CableCompOption::Internal => "internal",
+            CableCompOption::External => "external",
+        })
+    }
+}
+
+impl core::fmt::Display for CableCompLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            CableCompLevel::V0p0 => "0 V",
+            CableCompLevel::V0p1 => "0.1 V",
+            CableCompLevel::V0p2 => "0.2 V",
+            CableCompLevel::V0p3 => "0.3 V",
+            CableCompLevel::V0p4 => "0.4 V",
+            CableCompLevel::V0p5 => "0.5 V",
+            CableCompLevel::V0p6 => "0.6 V",
+            CableCompLevel::V0p7 => "0.7 V",
+        })
+    }
+}
+
+impl core::fmt::Display for OperatingStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::fmt::Display for StatusReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "mode={} faults={} oe={} light_load={} vcc={} addr={:#04x}",
+            self.operating.as_str(),
+            self.faults.summary(),
+            if self.output_enabled { "on" } else { "off" },
+            self.light_load_label(),
+            self.vcc_source.as_str(),
+            self.address
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for StatusReport {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "mode={=str} faults={=str} oe={=str} light_load={=str} vcc={=str} addr={=u8:#04x}",
+            self.operating.as_str(),
+            self.faults.summary(),
+            if self.output_enabled { "on" } else { "off" },
+            self.light_load_label(),
+            self.vcc_source.as_str(),
+            self.address
+        );
+    }
+}
+
+/// Raw copy of all eight registers (REF0..STATUS), as returned by `Tps55288::read_all`.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RegisterSnapshot {
+    pub ref0: u8,
+    pub ref1: u8,
+    pub iout_limit: u8,
+    pub vout_sr: u8,
+    pub vout_fs: u8,
+    pub cdc: u8,
+    pub mode: u8,
+    pub status: u8,
+}
+
+impl RegisterSnapshot {
+    /// Build a snapshot from a burst read starting at REF0.
+    pub fn from_bytes(bytes: [u8; 8]) -> Self {
+        let [ref0, ref1, iout_limit, vout_sr, vout_fs, cdc, mode, status] = bytes;
+        Self {
+            ref0,
+            ref1,
+            iout_limit,
+            vout_sr,
+            vout_fs,
+            cdc,
+            mode,
+            status,
+        }
+    }
+
+    /// Register contents in address order (REF0 first).
+    pub fn to_bytes(&self) -> [u8; 8] {
+        [
+            self.ref0,
+            self.ref1,
+            self.iout_limit,
+            self.vout_sr,
+            self.vout_fs,
+            self.cdc,
+            self.mode,
+            self.status,
+        ]
+    }
+
+    /// 10-bit REF DAC code.
+    pub fn vout_code(&self) -> u16 {
+        u16::from_le_bytes([self.ref0, self.ref1]) & REF_CODE_MAX
+    }
+
+    /// Current limit as `(mA, enabled)`, assuming a 10 mΩ sense resistor.
+    pub fn ilim(&self) -> (u16, bool) {
+        (
+            code_to_ilim_ma(self.iout_limit & ILIM_MAX_CODE),
+            self.iout_limit & IoutLimitBits::EN.bits() != 0,
+        )
+    }
+
+    /// Decoded MODE register.
+    pub fn mode_config(&self) -> ModeConfig {
+        ModeConfig::from_bits(ModeBits::from_bits_truncate(self.mode))
+    }
+
+    /// Decoded STATUS register.
+    pub fn status(&self) -> (OperatingStatus, FaultStatus) {
+        let bits = StatusBits::from_bits_retain(self.status);
+        (decode_status(&bits), FaultStatus::from_bits(bits))
+    }
+}
+
+/// Registers a [`Config`](crate::Config) would change, as returned by
+/// `Tps55288::config_diff`.
+///
+/// Covers REF0..MODE; `status` is 0 on both sides because STATUS is never read. Feed
+/// `current` and `target` to `Tps55288::apply_diff` to write only what changed.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConfigDiff {
+    /// Register contents read from the device.
+    pub current: RegisterSnapshot,
+    /// Register contents after applying the config.
+    pub target: RegisterSnapshot,
+}
+
+impl ConfigDiff {
+    /// True when applying the config would leave every register as it is.
+    pub fn is_empty(&self) -> bool {
+        self.changes().next().is_none()
+    }
+
+    /// Differing registers as `(address, current, target)`, in address order.
+    pub fn changes(&self) -> impl Iterator<Item = (u8, u8, u8)> {
+        let current = self.current.to_bytes();
+        let target = self.target.to_bytes();
+        (0..7u8).filter_map(move |reg| {
+            let (from, to) = (current[reg as usize], target[reg as usize]);
+            (from != to).then_some((reg, from, to))
+        })
+    }
+}
+
+impl core::fmt::Display for ConfigDiff {
+    /// One `reg: from -> to` entry per differing register, or `no changes`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no changes");
+        }
+        for (i, (reg, from, to)) in self.changes().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "0x{:02X}: 0x{:02X} -> 0x{:02X}", reg, from, to)?;
+        }
+        Ok(())
+    }
+}
+
 /// Placeholder for operating status bits (to be populated from STATUS register details).
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct StatusFlags {
     pub raw: u8,
 }
 
 /// Placeholder for fault flags (write-1-to-clear in STATUS).
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct FaultFlags {
     pub raw: u8,
 }
 
+/// Output voltage in millivolts; distinct from [`Milliamps`] so the two cannot be swapped.
+///
+/// ```compile_fail
+/// use tps55288::data_types::{Milliamps, Millivolts};
+/// let vout: Millivolts = Milliamps(3_000);
+/// ```
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Millivolts(pub u16);
+
+/// Current in milliamps; distinct from [`Millivolts`] so the two cannot be swapped.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Milliamps(pub u16);
+
+impl From<Millivolts> for u16 {
+    fn from(v: Millivolts) -> Self {
+        v.0
+    }
+}
+
+impl From<Milliamps> for u16 {
+    fn from(i: Milliamps) -> Self {
+        i.0
+    }
+}
+
+/// Round a non-negative `f32` to the nearest `u16`, saturating (`f32::round` needs `std`).
+#[cfg(feature = "uom")]
+fn round_to_u16(x: f32) -> u16 {
+    (x + 0.5) as u16
+}
+
+#[cfg(feature = "uom")]
+impl From<uom::si::f32::ElectricPotential> for Millivolts {
+    fn from(v: uom::si::f32::ElectricPotential) -> Self {
+        Millivolts(round_to_u16(
+            v.get::<uom::si::electric_potential::millivolt>(),
+        ))
+    }
+}
+
+#[cfg(feature = "uom")]
+impl From<uom::si::f32::ElectricCurrent> for Milliamps {
+    fn from(i: uom::si::f32::ElectricCurrent) -> Self {
+        Milliamps(round_to_u16(
+            i.get::<uom::si::electric_current::milliampere>(),
+        ))
+    }
+}
+
 /// VOUT configuration placeholder.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct VoutSetting {
     pub millivolts: u16,
 }
 
 /// Output current limit configuration placeholder.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct CurrentLimitSetting {
     pub milliamps: u16,
 }
+
+/// Which I2C failures the register accessors retry, and how often.
+///
+/// `Default` makes a single attempt, i.e. no retries. NACKs are never retried, so a missing
+/// device still fails fast (see `Tps55288::enable_output_retry` for cold-start NACKs).
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts per access, including the first; 0 behaves like 1.
+    pub max_attempts: u8,
+    /// Retry `ErrorKind::ArbitrationLoss` (another master won the bus).
+    pub retry_arbitration_loss: bool,
+    /// Retry `ErrorKind::Bus` and `ErrorKind::Other`, where HALs usually report timeouts and
+    /// clock-stretch faults.
+    pub retry_bus_errors: bool,
+}
+
+impl RetryPolicy {
+    /// Retry arbitration loss and bus/timeout errors up to `max_attempts` in total.
+    pub fn new(max_attempts: u8) -> Self {
+        Self {
+            max_attempts,
+            retry_arbitration_loss: true,
+            retry_bus_errors: true,
+        }
+    }
+
+    /// True if a failure of `kind` on attempt number `attempt` (1-based) may be retried.
+    pub fn should_retry(&self, attempt: u8, kind: embedded_hal::i2c::ErrorKind) -> bool {
+        use embedded_hal::i2c::ErrorKind;
+
+        attempt < self.max_attempts
+            && match kind {
+                ErrorKind::ArbitrationLoss => self.retry_arbitration_loss,
+                ErrorKind::Bus | ErrorKind::Other => self.retry_bus_errors,
+                _ => false,
+            }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            retry_arbitration_loss: false,
+            retry_bus_errors: false,
+        }
+    }
+}
@@ -1,36 +1,197 @@
 //! Driver scaffold for TPS55288.
-//! Provides blocking I2C helpers; async version will mirror this API behind the `async` feature.
+//! The driver methods are written once as async code; `maybe-async-cfg` emits the blocking
+//! version by default and keeps the async version behind the `async` feature.
 
+use core::marker::PhantomData;
+
+use crate::config::Config;
 use crate::data_types::{
-    CableCompLevel, CableCompOption, FaultStatus, FeedbackSource, I2cAddress,
-    InternalFeedbackRatio, LightLoadMode, LightLoadOverride, OcpDelay, OperatingStatus, VccSource,
-    VoutSlewRate,
+    CableCompLevel, CableCompOption, CableCompSettings, ConfigDiff, Fault, FaultMaskConfig,
+    FaultStatus, FeedbackSource, I2cAddress, InternalFeedbackRatio, LightLoadMode,
+    LightLoadOverride, Milliamps, Millivolts, ModeConfig, ModePreset, OcpDelay, OperatingStatus,
+    RegisterSnapshot, RetryPolicy, StatusReport, VccSource, VoutSlewRate, address_for_preset,
 };
 use crate::error::Error;
 use crate::registers::{
-    ALT_I2C_ADDRESS, CdcBits, DEFAULT_I2C_ADDRESS, IoutLimitBits, ModeBits, StatusBits, VoutFsBits,
-    VoutSrBits, addr, code_to_ilim_ma, code_to_vout_mv, decode_status_mode, ilim_ma_to_code,
-    vout_mv_to_code,
+    ALT_I2C_ADDRESS, CANDIDATE_I2C_ADDRESSES, CdcBits, DEFAULT_I2C_ADDRESS, DEFAULT_RSENSE_MOHM,
+    ILIM_MAX_CODE, IoutLimitBits, MIN_USEFUL_ILIM_CODE, ModeBits, REF_CODE_MAX, RESERVED_MASKS,
+    RESET_CDC, RESET_IOUT_LIMIT, RESET_MODE, RESET_REF0, RESET_REF1, RESET_VALUES, RESET_VOUT_FS,
+    RESET_VOUT_SR, Register, STATUS_FAULT_MASK, StatusBits, VOUT_MIN_MV, VOUT_SPEC_MAX_MV,
+    VoutFsBits, VoutRefBits, VoutSrBits, VoutSrConfig, addr, code_to_ilim_ma_for_sense,
+    code_to_vout_mv_for_ratio, decode_status, ilim_ma_to_code_for_sense,
+    ref_code_for_external_vout, vout_mv_to_code_for_ratio, vout_range_for_ratio, vout_slew_time_us,
 };
 
+use embedded_hal::i2c::{Error as _, Operation};
+#[cfg(not(feature = "async"))]
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+#[cfg(feature = "async")]
+use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+
+/// Typestate marker: the driver has not applied a configuration yet.
+///
+/// The typestate is opt-in: `new` and `with_address` still return a [`Configured`] driver,
+/// so only code that starts from `Tps55288::unconfigured` gets this guarantee.
+///
+/// Only `configure`, `force` and `free` are available, so OE cannot be set early:
+///
+/// ```compile_fail
+/// use embedded_hal_mock::eh1::i2c::Mock;
+/// use tps55288::driver::Tps55288;
+///
+/// let mut dev = Tps55288::unconfigured(Mock::new(&[]));
+/// let _ = dev.enable_output();
+/// ```
+pub struct Unconfigured;
+
+/// Typestate marker: the full register API is available (the default state).
+pub struct Configured;
+
 /// TPS55288 driver placeholder.
-pub struct Tps55288<I2C> {
+///
+/// `Tps55288<I2C>` is the configured driver, and `new`/`with_address` return it directly
+/// without touching the device. `Tps55288::unconfigured` starts in the [`Unconfigured`] state
+/// for code that opts in to having configuration precede OE by construction.
+pub struct Tps55288<I2C, State = Configured> {
     i2c: I2C,
     address: u8,
+    /// Feedback source last written via `set_feedback` (reset value internal).
+    feedback_source: FeedbackSource,
+    /// Internal feedback ratio last written via `set_feedback` (reset value 0.0564).
+    feedback_ratio: InternalFeedbackRatio,
+    /// Sense resistor (mΩ) used for the milliamp current-limit conversions.
+    rsense_mohm: u16,
+    /// Per-unit VOUT correction (mV) added before the REF code is computed.
+    vref_trim_mv: i16,
+    /// Retry behaviour of the register accessors.
+    retry: RetryPolicy,
+    _state: PhantomData<State>,
 }
 
 impl<I2C> Tps55288<I2C> {
     /// Create a new driver instance with the default I2C address (0x74).
     pub fn new(i2c: I2C) -> Self {
+        Self::with_address(i2c, DEFAULT_I2C_ADDRESS)
+    }
+
+    /// Create a new driver instance with a custom I2C address.
+    pub fn with_address(i2c: I2C, address: u8) -> Self {
         Self {
             i2c,
-            address: DEFAULT_I2C_ADDRESS,
+            address,
+            feedback_source: FeedbackSource::Internal,
+            feedback_ratio: InternalFeedbackRatio::R0_0564,
+            rsense_mohm: DEFAULT_RSENSE_MOHM,
+            vref_trim_mv: 0,
+            retry: RetryPolicy::default(),
+            _state: PhantomData,
         }
     }
 
-    /// Create a new driver instance with a custom I2C address.
-    pub fn with_address(i2c: I2C, address: u8) -> Self {
-        Self { i2c, address }
+    /// Use a different current sense resistor (mΩ) for `set_ilim_ma`/`get_ilim_ma` and the
+    /// `Config` based setup; the default is 10 mΩ.
+    ///
+    /// # Panics
+    /// Panics if `rsense_mohm` is zero.
+    #[must_use]
+    pub fn with_sense_resistor(mut self, rsense_mohm: u16) -> Self {
+        assert!(rsense_mohm != 0, "sense resistor must be non-zero");
+        self.rsense_mohm = rsense_mohm;
+        self
+    }
+
+    /// Sense resistor (mΩ) assumed by the milliamp current-limit methods.
+    pub fn sense_resistor_mohm(&self) -> u16 {
+        self.rsense_mohm
+    }
+
+    /// Apply a per-unit VOUT correction (mV) to every millivolt REF path: `set_vout_mv` and its
+    /// variants, and the `Config` helpers (`init_with_config`, `write_config_burst`,
+    /// `verify_config`).
+    ///
+    /// The offset is added to the requested voltage before the REF code is computed, so with
+    /// the 0.0564 ratio +40 mV raises the code by two 20 mV LSBs. The result is still clamped
+    /// to the DAC range. The driver only applies the trim; persisting it is up to the caller.
+    pub fn set_vref_trim_mv(&mut self, offset_mv: i16) {
+        self.vref_trim_mv = offset_mv;
+    }
+
+    /// VOUT correction (mV) currently applied by `set_vout_mv`.
+    pub fn vref_trim_mv(&self) -> i16 {
+        self.vref_trim_mv
+    }
+
+    /// `mv` with the VOUT trim applied, before any clamping.
+    fn trimmed_mv(&self, mv: u16) -> i32 {
+        mv as i32 + self.vref_trim_mv as i32
+    }
+
+    /// REF code for `mv` at `ratio` after applying the VOUT trim.
+    ///
+    /// Every REF computation from millivolts goes through here so the trim is applied once.
+    fn trimmed_vout_code(&self, mv: u16, ratio: InternalFeedbackRatio) -> u16 {
+        let trimmed = self.trimmed_mv(mv).clamp(0, u16::MAX as i32);
+        vout_mv_to_code_for_ratio(trimmed as u16, ratio)
+    }
+
+    /// REF0..CDC register values for a [`Config`], in address order.
+    ///
+    /// `ref1` is the current REF1 contents; its bits outside the DAC field are kept.
+    fn config_bytes(&self, cfg: &Config, ref1: u8) -> [u8; 6] {
+        let [ref0, ref1] = ref_bytes(
+            self.trimmed_vout_code(cfg.vout_mv, cfg.feedback_ratio),
+            ref1,
+        );
+        let mut ilim = ilim_ma_to_code_for_sense(cfg.ilim_ma, self.rsense_mohm);
+        if cfg.ilim_enabled {
+            ilim |= IoutLimitBits::EN.bits();
+        }
+        [
+            ref0,
+            ref1,
+            ilim,
+            VoutSrConfig::new()
+                .slew(cfg.slew_rate)
+                .ocp_delay(cfg.ocp_delay)
+                .build()
+                .sanitize()
+                .bits(),
+            vout_fs_bits(cfg.feedback_source, cfg.feedback_ratio).bits(),
+            cdc_bits(
+                cfg.cable_comp_option,
+                cfg.cable_comp_level,
+                cfg.mask_sc,
+                cfg.mask_ocp,
+                cfg.mask_ovp,
+            )
+            .bits(),
+        ]
+    }
+
+    /// Register image `init_with_config(cfg)` would produce on top of `current`.
+    ///
+    /// REF0..CDC come from `config_bytes` and MODE from `config_mode`, the same sources
+    /// `write_config_burst` and `verify_config` use. STATUS is copied from `current`.
+    fn config_image(&self, cfg: &Config, current: &RegisterSnapshot) -> RegisterSnapshot {
+        let mut bytes = current.to_bytes();
+        bytes[..6].copy_from_slice(&self.config_bytes(cfg, current.ref1));
+        bytes[6] = config_mode(cfg, current.mode);
+        RegisterSnapshot::from_bytes(bytes)
+    }
+
+    /// Retry failed register accesses according to `policy` (default: no retries).
+    ///
+    /// Applies to `read_reg`/`write_reg`/`read_regs`/`write_regs` and everything built on
+    /// them; raw transactions such as `probe` are not retried.
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Retry policy applied by the register accessors.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry
     }
 
     /// Return the 7-bit I2C address configured for this instance.
@@ -43,6 +204,37 @@ impl<I2C> Tps55288<I2C> {
         self.address = address;
     }
 
+    /// Internal feedback ratio assumed by `set_vout_mv` (last value passed to `set_feedback`).
+    pub fn feedback_ratio(&self) -> InternalFeedbackRatio {
+        self.feedback_ratio
+    }
+
+    /// Expected time (µs) for VOUT to move `delta_mv` at `slew`; see `vout_slew_time_us`.
+    pub fn vout_settle_time_us(&self, delta_mv: u16, slew: VoutSlewRate) -> u32 {
+        vout_slew_time_us(delta_mv, slew)
+    }
+
+    /// Input current (mA) needed to deliver `iout_ma` at `vout_mv` from `vin_mv`.
+    ///
+    /// Pin = Pout / η, so Iin = Vout · Iout · 100 / (Vin · `efficiency_pct`), rounded up so the
+    /// result is safe for sizing an upstream current limit. `efficiency_pct` is clamped to
+    /// 1..=100; a zero `vin_mv` or an overflowing result saturates at `u16::MAX`.
+    pub fn estimated_iin_ma(
+        &self,
+        vin_mv: u16,
+        vout_mv: u16,
+        iout_ma: u16,
+        efficiency_pct: u8,
+    ) -> u16 {
+        if vin_mv == 0 {
+            return u16::MAX;
+        }
+        let eff = efficiency_pct.clamp(1, 100) as u64;
+        let pout = vout_mv as u64 * iout_ma as u64 * 100;
+        let iin = pout.div_ceil(vin_mv as u64 * eff);
+        iin.min(u16::MAX as u64) as u16
+    }
+
     /// Quick helper: select default address (0x74).
     pub fn select_default_address(&mut self) {
         self.address = DEFAULT_I2C_ADDRESS;
@@ -53,64 +245,421 @@ impl<I2C> Tps55288<I2C> {
         self.address = ALT_I2C_ADDRESS;
     }
 
+    /// Target the boot address implied by a MODE-pin preset.
+    pub fn select_address_for_preset(&mut self, preset: &ModePreset) {
+        self.address = address_for_preset(preset);
+    }
+
     /// Consume the driver and return the underlying I2C bus.
     pub fn free(self) -> I2C {
         self.i2c
     }
+
+    /// Move the driver onto another bus handle, e.g. from a blocking bootloader I2C to the
+    /// application's async one.
+    ///
+    /// The address, cached feedback ratio and sense resistor carry over; the old bus is dropped
+    /// (use `free` first if it must be recovered). The struct itself does not depend on which
+    /// I2C trait the bus implements, so `new`/`with_address` work with either.
+    pub fn rebind<I2C2>(self, i2c: I2C2) -> Tps55288<I2C2> {
+        self.copy_onto(i2c)
+    }
 }
 
-#[cfg(not(feature = "async"))]
-impl<I2C> Tps55288<I2C>
-where
-    I2C: embedded_hal::i2c::I2c,
-{
-    /// Enable output (set OE=1 in MODE register, preserving other bits).
-    pub fn enable_output(&mut self) -> Result<(), Error<I2C::Error>> {
-        let mut mode = ModeBits::from_bits_truncate(self.read_reg(addr::MODE)?);
+impl<I2C> Tps55288<I2C, Unconfigured> {
+    /// Create a driver at the default address (0x74) that must be configured before use.
+    pub fn unconfigured(i2c: I2C) -> Self {
+        Tps55288::new(i2c).into_state()
+    }
+
+    /// Skip configuration and unlock the full API, e.g. when a bootloader already set the
+    /// device up.
+    pub fn force(self) -> Tps55288<I2C> {
+        self.into_state()
+    }
+
+    /// Consume the driver and return the underlying I2C bus.
+    pub fn free(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C, S> Tps55288<I2C, S> {
+    fn into_state<T>(self) -> Tps55288<I2C, T> {
+        let settings: Tps55288<(), T> = self.copy_onto(());
+        settings.copy_onto(self.i2c)
+    }
+
+    /// Copy the address and cached settings onto a driver for `i2c` in state `T`.
+    ///
+    /// The one place that lists the non-bus fields: the pattern has no `..`, so a new field
+    /// fails to compile here instead of being dropped by `rebind`, `into_state` or the shared
+    /// adapter.
+    pub(crate) fn copy_onto<I2C2, T>(&self, i2c: I2C2) -> Tps55288<I2C2, T> {
+        let Tps55288 {
+            i2c: _,
+            address,
+            feedback_source,
+            feedback_ratio,
+            rsense_mohm,
+            vref_trim_mv,
+            retry,
+            _state: _,
+        } = *self;
+        Tps55288 {
+            i2c,
+            address,
+            feedback_source,
+            feedback_ratio,
+            rsense_mohm,
+            vref_trim_mv,
+            retry,
+            _state: PhantomData,
+        }
+    }
+}
+
+/// Collects the construction options of [`Tps55288`] in one place.
+///
+/// `build` only assembles the driver; `build_and_init` also applies the stored [`Config`].
+#[derive(Clone, Copy, Debug)]
+pub struct Tps55288Builder {
+    address: u8,
+    rsense_mohm: u16,
+    config: Option<Config>,
+}
+
+impl Tps55288Builder {
+    /// Default address (0x74), 10 mΩ sense resistor, no configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 7-bit I2C address to talk to.
+    #[must_use]
+    pub fn address(mut self, address: u8) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Current sense resistor (mΩ); see `Tps55288::with_sense_resistor`.
+    ///
+    /// # Panics
+    /// Panics if `rsense_mohm` is zero.
+    #[must_use]
+    pub fn rsense_mohm(mut self, rsense_mohm: u16) -> Self {
+        assert!(rsense_mohm != 0, "sense resistor must be non-zero");
+        self.rsense_mohm = rsense_mohm;
+        self
+    }
+
+    /// Configuration applied by `build_and_init`.
+    #[must_use]
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Assemble the driver without touching the bus; any stored config is not applied.
+    pub fn build<I2C>(self, i2c: I2C) -> Tps55288<I2C> {
+        Tps55288::with_address(i2c, self.address).with_sense_resistor(self.rsense_mohm)
+    }
+}
+
+impl Default for Tps55288Builder {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_I2C_ADDRESS,
+            rsense_mohm: DEFAULT_RSENSE_MOHM,
+            config: None,
+        }
+    }
+}
+
+/// True when an I2C error means the addressed device did not acknowledge.
+fn is_nack<E: embedded_hal::i2c::Error>(e: &E) -> bool {
+    matches!(e.kind(), embedded_hal::i2c::ErrorKind::NoAcknowledge(_))
+}
+
+/// `Error::I2c` for a failed `op` ("read"/"write") at `reg`.
+///
+/// With `defmt` the register and direction are logged first, since the bus error alone does
+/// not say which access failed; otherwise this is a plain `Error::I2c`.
+fn bus_error<E>(reg: u8, op: &'static str) -> impl FnOnce(E) -> Error<E> {
+    move |e| {
+        #[cfg(feature = "defmt")]
+        defmt::warn!("tps55288: I2C {=str} at reg {=u8:#04x} failed", op, reg);
+        #[cfg(not(feature = "defmt"))]
+        let _ = (reg, op);
+        Error::I2c(e)
+    }
+}
+
+/// VOUT_FS encoding of a feedback source and internal ratio.
+///
+/// The chip ignores INTFB in external mode, so the field is written as 0 there.
+fn vout_fs_bits(source: FeedbackSource, ratio: InternalFeedbackRatio) -> VoutFsBits {
+    if matches!(source, FeedbackSource::External) {
+        return VoutFsBits::FB_EXT;
+    }
+    match ratio {
+        InternalFeedbackRatio::R0_2256 => VoutFsBits::empty(),
+        InternalFeedbackRatio::R0_1128 => VoutFsBits::INTFB0,
+        InternalFeedbackRatio::R0_0752 => VoutFsBits::INTFB1,
+        InternalFeedbackRatio::R0_0564 => VoutFsBits::INTFB0 | VoutFsBits::INTFB1,
+    }
+}
+
+/// CDC encoding of cable compensation settings and fault masks.
+fn cdc_bits(
+    option: CableCompOption,
+    level: CableCompLevel,
+    mask_sc: bool,
+    mask_ocp: bool,
+    mask_ovp: bool,
+) -> CdcBits {
+    let mut bits = CdcBits::empty();
+    if mask_sc {
+        bits |= CdcBits::SC_MASK;
+    }
+    if mask_ocp {
+        bits |= CdcBits::OCP_MASK;
+    }
+    if mask_ovp {
+        bits |= CdcBits::OVP_MASK;
+    }
+    if matches!(option, CableCompOption::External) {
+        bits |= CdcBits::CDC_OPT;
+    }
+    let level_bits = match level {
+        CableCompLevel::V0p0 => CdcBits::empty(),
+        CableCompLevel::V0p1 => CdcBits::CDC0,
+        CableCompLevel::V0p2 => CdcBits::CDC1,
+        CableCompLevel::V0p3 => CdcBits::CDC0 | CdcBits::CDC1,
+        CableCompLevel::V0p4 => CdcBits::CDC2,
+        CableCompLevel::V0p5 => CdcBits::CDC2 | CdcBits::CDC0,
+        CableCompLevel::V0p6 => CdcBits::CDC2 | CdcBits::CDC1,
+        CableCompLevel::V0p7 => CdcBits::CDC2 | CdcBits::CDC1 | CdcBits::CDC0,
+    };
+    bits | level_bits
+}
+
+/// REF0/REF1 bytes for a 10-bit DAC code, keeping the bits of `ref1` outside `VoutRefBits`.
+fn ref_bytes(code: u16, ref1: u8) -> [u8; 2] {
+    let [ref0, msb] = (code & REF_CODE_MAX).to_le_bytes();
+    let dac_mask = VoutRefBits::all().bits();
+    [ref0, (ref1 & !dac_mask) | (msb & dac_mask)]
+}
+
+/// MODE after applying the bits a [`Config`] controls to `current`: OE when `enable_output` is
+/// set, MODE bit0 plus PFM when `light_load_mode` is set.
+fn config_mode(cfg: &Config, current: u8) -> u8 {
+    let mut mode = ModeBits::from_bits_retain(current);
+    if let Some(light_load) = cfg.light_load_mode {
+        mode.insert(ModeBits::MODE);
+        mode.set(ModeBits::PFM, matches!(light_load, LightLoadMode::Pwm));
+    }
+    if cfg.enable_output {
         mode.insert(ModeBits::OE);
-        self.write_reg(addr::MODE, mode.bits())
     }
+    mode.bits()
+}
 
-    /// Disable output (set OE=0 in MODE register, preserving other bits).
-    pub fn disable_output(&mut self) -> Result<(), Error<I2C::Error>> {
-        let mut mode = ModeBits::from_bits_truncate(self.read_reg(addr::MODE)?);
-        mode.remove(ModeBits::OE);
-        self.write_reg(addr::MODE, mode.bits())
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), keep_self),
+    async(feature = "async", keep_self)
+)]
+impl Tps55288Builder {
+    /// `build`, then apply the stored config (if any) through `init_with_config`.
+    pub async fn build_and_init<I2C: I2c>(
+        self,
+        i2c: I2C,
+    ) -> Result<Tps55288<I2C>, Error<I2C::Error>> {
+        let mut dev = self.build(i2c);
+        if let Some(cfg) = &self.config {
+            dev.init_with_config(cfg).await?;
+        }
+        Ok(dev)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), keep_self),
+    async(feature = "async", keep_self)
+)]
+impl<I2C> Tps55288<I2C, Unconfigured>
+where
+    I2C: I2c,
+{
+    /// Apply `cfg` through `init_with_config` and unlock the full API.
+    ///
+    /// OE is set only if `cfg.enable_output` asks for it, after everything else. On error the
+    /// driver comes back still `Unconfigured` alongside the error, so the caller can retry or
+    /// `free` the bus; the device may have taken part of the configuration by then.
+    #[allow(clippy::type_complexity)]
+    pub async fn configure(
+        self,
+        cfg: &Config,
+    ) -> Result<Tps55288<I2C>, (Tps55288<I2C, Unconfigured>, Error<I2C::Error>)> {
+        let mut dev = self.force();
+        match dev.init_with_config(cfg).await {
+            Ok(()) => Ok(dev),
+            Err(e) => Err((dev.into_state(), e)),
+        }
     }
+}
 
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), keep_self),
+    async(feature = "async", keep_self)
+)]
+impl<I2C> Tps55288<I2C>
+where
+    I2C: I2c,
+{
     /// Initialize device with safe defaults (current limit enabled, default VOUT).
     ///
     /// Note: OE is left **disabled** here on purpose so that callers can
     /// finish all configuration first and then explicitly enable the output.
-    pub fn init(&mut self) -> Result<(), Error<I2C::Error>> {
+    pub async fn init(&mut self) -> Result<(), Error<I2C::Error>> {
         // Enable current limit with default 50 mV (datasheet reset value) to avoid uncontrolled current.
-        self.write_reg(addr::IOUT_LIMIT, IoutLimitBits::EN.bits() | 0b1100100)?;
+        self.write_reg(addr::IOUT_LIMIT, IoutLimitBits::EN.bits() | 0b1100100)
+            .await?;
         // Set default VOUT to datasheet reset (REF reset = 0x0000 -> ~0.8 V). Caller should override for actual use.
-        self.set_vout_mv(crate::registers::VOUT_MIN_MV)?;
+        self.set_vout_mv(crate::registers::VOUT_MIN_MV).await?;
+        Ok(())
+    }
+
+    /// Program a complete [`Config`] in a safe order.
+    ///
+    /// The datasheet requires OCP_MASK = 0 while OE or Current_Limit_EN goes from 0 to 1, so
+    /// CDC is written first with OCP_MASK cleared. The current limit follows so the power stage
+    /// is never unlimited, then feedback, slew rate, VOUT and light-load mode. OE is set next,
+    /// only when `cfg.enable_output` is true, and OCP_MASK is set last if `cfg.mask_ocp` asks
+    /// for it.
+    pub async fn init_with_config(&mut self, cfg: &Config) -> Result<(), Error<I2C::Error>> {
+        self.set_cable_comp(
+            cfg.cable_comp_option,
+            cfg.cable_comp_level,
+            cfg.mask_sc,
+            false,
+            cfg.mask_ovp,
+        )
+        .await?;
+        self.set_ilim_ma(cfg.ilim_ma, cfg.ilim_enabled).await?;
+        self.set_feedback(cfg.feedback_source, cfg.feedback_ratio)
+            .await?;
+        self.set_vout_sr(cfg.slew_rate, cfg.ocp_delay).await?;
+        self.set_vout_mv(cfg.vout_mv).await?;
+        if let Some(mode) = cfg.light_load_mode {
+            self.set_light_load_mode(LightLoadOverride::FromRegister, mode)
+                .await?;
+        }
+        if cfg.enable_output {
+            self.enable_output().await?;
+        }
+        if cfg.mask_ocp {
+            self.set_cable_comp(
+                cfg.cable_comp_option,
+                cfg.cable_comp_level,
+                cfg.mask_sc,
+                true,
+                cfg.mask_ovp,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Enable output (set OE=1 in MODE register, preserving other bits).
+    pub async fn enable_output(&mut self) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        mode.insert(ModeBits::OE);
+        self.write_reg(addr::MODE, mode.bits()).await
+    }
+
+    /// `enable_output`, retried up to `retries` more times with `retry_delay_us` between
+    /// attempts.
+    ///
+    /// For cold starts where the first MODE access can race the internal LDO and NACK. Each
+    /// attempt repeats the full read-modify-write; the last error is returned if all fail.
+    pub async fn enable_output_retry<D: DelayNs>(
+        &mut self,
+        retries: u8,
+        retry_delay_us: u32,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut attempt = 0;
+        loop {
+            match self.enable_output().await {
+                Err(_) if attempt < retries => {
+                    attempt += 1;
+                    delay.delay_us(retry_delay_us).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// `enable_output`, then read MODE back and return `Error::InvalidConfig` if OE did not
+    /// stick.
+    ///
+    /// Catches boards where the MODE write is acknowledged but OE is gated off, e.g. by a
+    /// miswired CE/EN line. Other MODE bits are not compared.
+    pub async fn enable_output_verified(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.enable_output().await?;
+        if !self.read_mode().await?.contains(ModeBits::OE) {
+            return Err(Error::InvalidConfig);
+        }
         Ok(())
     }
 
+    /// Disable output (set OE=0 in MODE register, preserving other bits).
+    pub async fn disable_output(&mut self) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        mode.remove(ModeBits::OE);
+        self.write_reg(addr::MODE, mode.bits()).await
+    }
+
+    /// Return whether the output is enabled (MODE bit7, OE).
+    pub async fn is_output_enabled(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mode = self.read_mode().await?;
+        Ok(mode.contains(ModeBits::OE))
+    }
+
     /// Write a single register.
-    pub fn write_reg(&mut self, reg: u8, value: u8) -> Result<(), Error<I2C::Error>> {
-        self.i2c
-            .write(self.address, &[reg, value])
-            .map_err(Error::I2c)
+    pub async fn write_reg(&mut self, reg: u8, value: u8) -> Result<(), Error<I2C::Error>> {
+        let mut attempt = 1;
+        loop {
+            match self.i2c.write(self.address, &[reg, value]).await {
+                Err(e) if self.retry.should_retry(attempt, e.kind()) => attempt += 1,
+                result => return result.map_err(bus_error(reg, "write")),
+            }
+        }
     }
 
     /// Read a single register.
-    pub fn read_reg(&mut self, reg: u8) -> Result<u8, Error<I2C::Error>> {
+    pub async fn read_reg(&mut self, reg: u8) -> Result<u8, Error<I2C::Error>> {
         let mut buf = [0u8; 1];
-        self.i2c
-            .write_read(self.address, &[reg], &mut buf)
-            .map_err(Error::I2c)?;
-        Ok(buf[0])
+        let mut attempt = 1;
+        loop {
+            match self.i2c.write_read(self.address, &[reg], &mut buf).await {
+                Err(e) if self.retry.should_retry(attempt, e.kind()) => attempt += 1,
+                result => return result.map(|()| buf[0]).map_err(bus_error(reg, "read")),
+            }
+        }
     }
 
     /// Update masked bits in a register (read-modify-write).
-    pub fn update_reg(&mut self, reg: u8, mask: u8, value: u8) -> Result<(), Error<I2C::Error>> {
-        let cur = self.read_reg(reg)?;
+    pub async fn update_reg(
+        &mut self,
+        reg: u8,
+        mask: u8,
+        value: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        let cur = self.read_reg(reg).await?;
         let new = (cur & !mask) | (value & mask);
-        self.write_reg(reg, new)
+        self.write_reg(reg, new).await
     }
 
     /// Configure the light-load operating mode (PFM/FPWM) via the MODE register.
@@ -120,13 +669,17 @@ where
     ///   (`FromPreset`) or by the MODE register itself (`FromRegister`).
     /// - PFM bit1 selects the light-load mode: 0 = PFM, 1 = forced PWM (FPWM).
     ///
+    /// Beware the inverted naming: setting the bit called `PFM` selects **FPWM**. This method
+    /// hides that, so `LightLoadMode::Pwm` always means forced PWM. Without the register override
+    /// the PFM bit has no effect and the MODE-pin preset decides.
+    ///
     /// To **force FPWM** through I2C, set `override_sel=FromRegister` and `mode=Pwm`.
-    pub fn set_light_load_mode(
+    pub async fn set_light_load_mode(
         &mut self,
         override_sel: LightLoadOverride,
         mode: LightLoadMode,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = ModeBits::from_bits_truncate(self.read_reg(addr::MODE)?);
+        let mut bits = self.read_mode().await?;
 
         match override_sel {
             LightLoadOverride::FromPreset => bits.remove(ModeBits::MODE),
@@ -138,7 +691,7 @@ where
             LightLoadMode::Pwm => bits.insert(ModeBits::PFM),
         }
 
-        self.write_reg(addr::MODE, bits.bits())
+        self.write_reg(addr::MODE, bits.bits()).await
     }
 
     /// Configure MODE register control source + the trio it gates (VCC/I2CADD/PFM).
@@ -150,14 +703,14 @@ where
     /// IMPORTANT: Once `override_sel=FromRegister`, the `vcc_source` and `address`
     /// bits are actively applied. Callers should set these explicitly to avoid
     /// accidentally switching the device's VCC source or I2C address.
-    pub fn set_mode_control(
+    pub async fn set_mode_control(
         &mut self,
         override_sel: LightLoadOverride,
         vcc_source: VccSource,
         address: I2cAddress,
         light_load_mode: LightLoadMode,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = ModeBits::from_bits_truncate(self.read_reg(addr::MODE)?);
+        let mut bits = self.read_mode().await?;
 
         match override_sel {
             LightLoadOverride::FromPreset => bits.remove(ModeBits::MODE),
@@ -179,106 +732,207 @@ where
             LightLoadMode::Pwm => bits.insert(ModeBits::PFM),
         }
 
-        self.write_reg(addr::MODE, bits.bits())
+        self.write_reg(addr::MODE, bits.bits()).await
     }
 
     /// Write a burst starting at a register (for multi-byte REF DAC etc.).
-    pub fn write_regs(&mut self, start_reg: u8, data: &[u8]) -> Result<(), Error<I2C::Error>> {
+    ///
+    /// The register byte and data share an 8-byte stack buffer, so at most 7 data bytes fit
+    /// (REF0..MODE); longer payloads return `Error::BufferTooSmall` with byte counts for the
+    /// whole frame.
+    pub async fn write_regs(
+        &mut self,
+        start_reg: u8,
+        data: &[u8],
+    ) -> Result<(), Error<I2C::Error>> {
         let mut buf = [0u8; 8];
         if data.len() + 1 > buf.len() {
-            // Small helper only; larger writes can stream directly in future.
-            return Err(Error::InvalidConfig);
+            return Err(Error::BufferTooSmall {
+                needed: data.len() + 1,
+                capacity: buf.len(),
+            });
         }
         buf[0] = start_reg;
         buf[1..=data.len()].copy_from_slice(data);
-        self.i2c
-            .write(self.address, &buf[..=data.len()])
-            .map_err(Error::I2c)
+        let mut attempt = 1;
+        loop {
+            match self.i2c.write(self.address, &buf[..=data.len()]).await {
+                Err(e) if self.retry.should_retry(attempt, e.kind()) => attempt += 1,
+                result => return result.map_err(bus_error(start_reg, "write")),
+            }
+        }
     }
 
     /// Read a burst starting at a register.
-    pub fn read_regs(&mut self, start_reg: u8, data: &mut [u8]) -> Result<(), Error<I2C::Error>> {
-        self.i2c
-            .write_read(self.address, &[start_reg], data)
-            .map_err(Error::I2c)
+    ///
+    /// Returns `Error::OutOfRange` if the burst would run past STATUS (0x07), the last
+    /// register in the map; use `read_regs_unchecked` to read beyond it anyway.
+    pub async fn read_regs(
+        &mut self,
+        start_reg: u8,
+        data: &mut [u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        if start_reg as usize + data.len() > addr::STATUS as usize + 1 {
+            return Err(Error::OutOfRange);
+        }
+        self.read_regs_unchecked(start_reg, data).await
+    }
+
+    /// `read_regs` without the register-map bounds check.
+    pub async fn read_regs_unchecked(
+        &mut self,
+        start_reg: u8,
+        data: &mut [u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut attempt = 1;
+        loop {
+            match self.i2c.write_read(self.address, &[start_reg], data).await {
+                Err(e) if self.retry.should_retry(attempt, e.kind()) => attempt += 1,
+                result => return result.map_err(bus_error(start_reg, "read")),
+            }
+        }
+    }
+
+    /// Typed form of `set_vout_mv`; with the `uom` feature it also accepts `ElectricPotential`.
+    pub async fn set_vout<V: Into<Millivolts>>(
+        &mut self,
+        vout: V,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.set_vout_mv(vout.into().0).await
     }
 
     /// Set output voltage (mV) using internal DAC (writes REF0/REF1).
-    pub fn set_vout_mv(&mut self, mv: u16) -> Result<(), Error<I2C::Error>> {
-        let code = vout_mv_to_code(mv);
-        let bytes = code.to_le_bytes();
-        self.write_regs(addr::REF0, &bytes)
+    ///
+    /// The DAC code is derived from the internal feedback ratio last configured through
+    /// `set_feedback` (0.0564 after reset). The write goes through `set_vout_code_unchecked`,
+    /// so REF1 bits outside the DAC field are preserved.
+    pub async fn set_vout_mv(&mut self, mv: u16) -> Result<(), Error<I2C::Error>> {
+        self.set_vout_mv_with_ratio(mv, self.feedback_ratio).await
+    }
+
+    /// Set output voltage (mV) for an explicit internal feedback ratio (VOUT = VREF / ratio).
+    ///
+    /// Does not touch VOUT_FS; use `set_feedback` to actually select the ratio. Any
+    /// `set_vref_trim_mv` offset is applied.
+    pub async fn set_vout_mv_with_ratio(
+        &mut self,
+        mv: u16,
+        ratio: InternalFeedbackRatio,
+    ) -> Result<(), Error<I2C::Error>> {
+        let code = self.trimmed_vout_code(mv, ratio);
+        self.set_vout_code_unchecked(code).await
+    }
+
+    /// Like `set_vout_mv`, but returns `Error::OutOfRange` instead of clamping when `mv` lies
+    /// outside the range of the cached feedback ratio (800..=21_260 mV for 0.0564).
+    ///
+    /// The check applies to the trimmed voltage, so a `set_vref_trim_mv` offset that would push
+    /// the code past the DAC range is rejected rather than clamped.
+    pub async fn set_vout_mv_checked(&mut self, mv: u16) -> Result<(), Error<I2C::Error>> {
+        let (min_mv, lsb_mv) = vout_range_for_ratio(self.feedback_ratio);
+        let max_mv = min_mv + 1023 * lsb_mv;
+        if !(min_mv as i32..=max_mv as i32).contains(&self.trimmed_mv(mv)) {
+            return Err(Error::OutOfRange);
+        }
+        self.set_vout_mv(mv).await
     }
 
     /// Read current VOUT setting (mV) from DAC registers.
-    pub fn get_vout_mv(&mut self) -> Result<u16, Error<I2C::Error>> {
-        let mut buf = [0u8; 2];
-        self.read_regs(addr::REF0, &mut buf)?;
-        let code = u16::from_le_bytes(buf);
-        Ok(code_to_vout_mv(code))
+    ///
+    /// Decoded with the cached internal feedback ratio, mirroring `set_vout_mv`. The value is
+    /// what the DAC holds, so it includes any `set_vref_trim_mv` offset.
+    pub async fn get_vout_mv(&mut self) -> Result<u16, Error<I2C::Error>> {
+        self.get_vout_mv_with_ratio(self.feedback_ratio).await
+    }
+
+    /// Read current VOUT setting (mV) for an explicit internal feedback ratio.
+    pub async fn get_vout_mv_with_ratio(
+        &mut self,
+        ratio: InternalFeedbackRatio,
+    ) -> Result<u16, Error<I2C::Error>> {
+        let code = self.get_vout_code().await?;
+        Ok(code_to_vout_mv_for_ratio(code, ratio))
+    }
+
+    /// Typed form of `set_ilim_ma`; with the `uom` feature it also accepts `ElectricCurrent`.
+    pub async fn set_ilim<A: Into<Milliamps>>(
+        &mut self,
+        limit: A,
+        enable: bool,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.set_ilim_ma(limit.into().0, enable).await
     }
 
     /// Configure output current limit (mA) and enable bit.
-    pub fn set_ilim_ma(&mut self, ma: u16, enable: bool) -> Result<(), Error<I2C::Error>> {
-        let code = ilim_ma_to_code(ma) & 0x7F;
+    ///
+    /// Converted with the driver's sense resistor (see `with_sense_resistor`).
+    pub async fn set_ilim_ma(&mut self, ma: u16, enable: bool) -> Result<(), Error<I2C::Error>> {
+        let code = ilim_ma_to_code_for_sense(ma, self.rsense_mohm);
         let mut val = code;
         if enable {
             val |= IoutLimitBits::EN.bits();
         }
-        self.write_reg(addr::IOUT_LIMIT, val)
+        self.write_reg(addr::IOUT_LIMIT, val).await
+    }
+
+    /// Like `set_ilim_ma`, but returns `Error::OutOfRange` above the largest settable limit
+    /// (`ILIM_MAX_MA` with the default 10 mΩ sense resistor) instead of clamping, and when the
+    /// converted code falls below `MIN_USEFUL_ILIM_CODE`, where the output would sit in
+    /// current limit. Both bounds follow the configured sense resistor.
+    pub async fn set_ilim_ma_checked(
+        &mut self,
+        ma: u16,
+        enable: bool,
+    ) -> Result<(), Error<I2C::Error>> {
+        if ilim_ma_to_code_for_sense(ma, self.rsense_mohm) < MIN_USEFUL_ILIM_CODE
+            || ma > code_to_ilim_ma_for_sense(ILIM_MAX_CODE, self.rsense_mohm)
+        {
+            return Err(Error::OutOfRange);
+        }
+        self.set_ilim_ma(ma, enable).await
     }
 
     /// Read output current limit configuration (mA, enable flag).
-    pub fn get_ilim_ma(&mut self) -> Result<(u16, bool), Error<I2C::Error>> {
-        let val = self.read_reg(addr::IOUT_LIMIT)?;
+    pub async fn get_ilim_ma(&mut self) -> Result<(u16, bool), Error<I2C::Error>> {
+        let val = self.read_reg(addr::IOUT_LIMIT).await?;
         let enable = (val & IoutLimitBits::EN.bits()) != 0;
-        let code = val & 0x7F;
-        Ok((code_to_ilim_ma(code), enable))
+        let code = val & ILIM_MAX_CODE;
+        Ok((code_to_ilim_ma_for_sense(code, self.rsense_mohm), enable))
     }
 
     /// Configure VOUT slew rate and OCP delay.
-    pub fn set_vout_sr(
+    pub async fn set_vout_sr(
         &mut self,
         slew: VoutSlewRate,
         ocp_delay: OcpDelay,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = VoutSrBits::empty();
-        bits |= match slew {
-            VoutSlewRate::Sr1p25MvPerUs => VoutSrBits::empty(),
-            VoutSlewRate::Sr2p5MvPerUs => VoutSrBits::SR0,
-            VoutSlewRate::Sr5MvPerUs => VoutSrBits::SR1,
-            VoutSlewRate::Sr10MvPerUs => VoutSrBits::SR0 | VoutSrBits::SR1,
-        };
-        bits |= match ocp_delay {
-            OcpDelay::Us128 => VoutSrBits::empty(),
-            OcpDelay::Ms3_072 => VoutSrBits::OCP_DELAY0,
-            OcpDelay::Ms6_144 => VoutSrBits::OCP_DELAY1,
-            OcpDelay::Ms12_288 => VoutSrBits::OCP_DELAY0 | VoutSrBits::OCP_DELAY1,
-        };
-        self.write_reg(addr::VOUT_SR, bits.bits())
+        let bits = VoutSrConfig::new()
+            .slew(slew)
+            .ocp_delay(ocp_delay)
+            .build()
+            .sanitize();
+        self.write_reg(addr::VOUT_SR, bits.bits()).await
     }
 
     /// Configure feedback source and internal divider ratio.
-    pub fn set_feedback(
+    ///
+    /// The ratio is cached so that subsequent `set_vout_mv` calls map millivolts correctly.
+    /// With `FeedbackSource::External` the INTFB field is written as 0 (the chip ignores it);
+    /// `set_external_feedback`/`set_internal_feedback` avoid passing a meaningless ratio.
+    pub async fn set_feedback(
         &mut self,
         source: FeedbackSource,
         ratio: InternalFeedbackRatio,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = VoutFsBits::empty();
-        if matches!(source, FeedbackSource::External) {
-            bits |= VoutFsBits::FB_EXT;
-        }
-        bits |= match ratio {
-            InternalFeedbackRatio::R0_2256 => VoutFsBits::empty(),
-            InternalFeedbackRatio::R0_1128 => VoutFsBits::INTFB0,
-            InternalFeedbackRatio::R0_0752 => VoutFsBits::INTFB1,
-            InternalFeedbackRatio::R0_0564 => VoutFsBits::INTFB0 | VoutFsBits::INTFB1,
-        };
-        self.write_reg(addr::VOUT_FS, bits.bits())
+        self.write_reg(addr::VOUT_FS, vout_fs_bits(source, ratio).bits())
+            .await?;
+        self.feedback_source = source;
+        self.feedback_ratio = ratio;
+        Ok(())
     }
 
     /// Configure cable droop compensation and fault masks.
-    pub fn set_cable_comp(
+    pub async fn set_cable_comp(
         &mut self,
         option: CableCompOption,
         level: CableCompLevel,
@@ -286,325 +940,1047 @@ where
         mask_ocp: bool,
         mask_ovp: bool,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = CdcBits::empty();
-        if mask_sc {
-            bits |= CdcBits::SC_MASK;
-        }
-        if mask_ocp {
-            bits |= CdcBits::OCP_MASK;
-        }
-        if mask_ovp {
-            bits |= CdcBits::OVP_MASK;
-        }
-        if matches!(option, CableCompOption::External) {
-            bits |= CdcBits::CDC_OPT;
-        }
-        let level_bits = match level {
-            CableCompLevel::V0p0 => CdcBits::empty(),
-            CableCompLevel::V0p1 => CdcBits::CDC0,
-            CableCompLevel::V0p2 => CdcBits::CDC1,
-            CableCompLevel::V0p3 => CdcBits::CDC0 | CdcBits::CDC1,
-            CableCompLevel::V0p4 => CdcBits::CDC2,
-            CableCompLevel::V0p5 => CdcBits::CDC2 | CdcBits::CDC0,
-            CableCompLevel::V0p6 => CdcBits::CDC2 | CdcBits::CDC1,
-            CableCompLevel::V0p7 => CdcBits::CDC2 | CdcBits::CDC1 | CdcBits::CDC0,
-        };
-        bits |= level_bits;
-        self.write_reg(addr::CDC, bits.bits())
+        let bits = cdc_bits(option, level, mask_sc, mask_ocp, mask_ovp);
+        self.write_reg(addr::CDC, bits.bits()).await
     }
 
     /// Read STATUS register raw bits.
-    pub fn read_status_raw(&mut self) -> Result<StatusBits, Error<I2C::Error>> {
-        let val = self.read_reg(addr::STATUS)?;
-        Ok(StatusBits::from_bits_truncate(val))
+    ///
+    /// Reserved bits 4-2 are retained (see `StatusBits::raw_reserved`).
+    pub async fn read_status_raw(&mut self) -> Result<StatusBits, Error<I2C::Error>> {
+        let val = self.read_reg(addr::STATUS).await?;
+        Ok(StatusBits::from_bits_retain(val))
     }
 
     /// Decode STATUS into user-friendly enums.
-    pub fn read_status(&mut self) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
-        let bits = self.read_status_raw()?;
-        let mode_bits = decode_status_mode(&bits);
-        let operating = match mode_bits {
-            0b00 => OperatingStatus::Boost,
-            0b01 => OperatingStatus::Buck,
-            0b10 => OperatingStatus::BuckBoost,
-            _ => OperatingStatus::Reserved,
-        };
-        let faults = FaultStatus {
-            short_circuit: bits.contains(StatusBits::SCP),
-            over_current: bits.contains(StatusBits::OCP),
-            over_voltage: bits.contains(StatusBits::OVP),
-        };
-        Ok((operating, faults))
+    pub async fn read_status(
+        &mut self,
+    ) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
+        let bits = self.read_status_raw().await?;
+        let operating = decode_status(&bits);
+        Ok((operating, FaultStatus::from_bits(bits)))
     }
-}
 
-#[cfg(feature = "async")]
-impl<I2C> Tps55288<I2C>
-where
-    I2C: embedded_hal_async::i2c::I2c,
-{
-    /// Initialize device with safe defaults (async build).
+    /// `read_status` plus the raw STATUS byte, from a single read.
     ///
-    /// Note: OE is left **disabled** here on purpose so that callers can
-    /// finish all configuration first and then explicitly enable the output.
-    pub async fn init(&mut self) -> Result<(), Error<I2C::Error>> {
-        self.write_reg(addr::IOUT_LIMIT, IoutLimitBits::EN.bits() | 0b1100100)
-            .await?;
-        self.set_vout_mv(crate::registers::VOUT_MIN_MV).await?;
-        Ok(())
+    /// The byte is unmasked, so reserved bits 4-2 show up as read.
+    pub async fn read_status_full(
+        &mut self,
+    ) -> Result<(OperatingStatus, FaultStatus, u8), Error<I2C::Error>> {
+        let bits = self.read_status_raw().await?;
+        let raw = bits.bits();
+        Ok((decode_status(&bits), FaultStatus::from_bits(bits), raw))
     }
 
-    /// Enable output (set OE=1 in MODE register, preserving other bits).
-    pub async fn enable_output(&mut self) -> Result<(), Error<I2C::Error>> {
-        let mut mode = ModeBits::from_bits_truncate(self.read_reg(addr::MODE).await?);
-        mode.insert(ModeBits::OE);
+    /// Clear all latched fault flags (SCP/OCP/OVP) in STATUS.
+    ///
+    /// Writes 1s to the fault bits only; reserved and operating-status bits are written as 0.
+    /// Note the datasheet also clears these flags on every STATUS read, so a fault that is
+    /// still present will simply latch again.
+    pub async fn clear_faults(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.clear_faults_selective(STATUS_FAULT_MASK).await
+    }
+
+    /// Clear only the fault flags present in `mask` (non-fault bits are ignored).
+    pub async fn clear_faults_selective(
+        &mut self,
+        mask: StatusBits,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_reg(addr::STATUS, (mask & STATUS_FAULT_MASK).bits())
+            .await
+    }
+
+    /// Select the VCC source via MODE bit3, preserving all other MODE bits.
+    ///
+    /// Only takes effect while MODE bit0 selects register control (`LightLoadOverride::FromRegister`);
+    /// otherwise the MODE-pin preset decides.
+    pub async fn set_vcc_source(&mut self, source: VccSource) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        match source {
+            VccSource::Internal => mode.remove(ModeBits::VCC_EXT),
+            VccSource::External5v => mode.insert(ModeBits::VCC_EXT),
+        }
         self.write_reg(addr::MODE, mode.bits()).await
     }
 
-    /// Disable output (set OE=0 in MODE register, preserving other bits).
-    pub async fn disable_output(&mut self) -> Result<(), Error<I2C::Error>> {
-        let mut mode = ModeBits::from_bits_truncate(self.read_reg(addr::MODE).await?);
-        mode.remove(ModeBits::OE);
+    /// Read the VCC source selected by MODE bit3.
+    pub async fn get_vcc_source(&mut self) -> Result<VccSource, Error<I2C::Error>> {
+        let mode = self.read_mode().await?;
+        Ok(if mode.contains(ModeBits::VCC_EXT) {
+            VccSource::External5v
+        } else {
+            VccSource::Internal
+        })
+    }
+
+    /// Enable or disable hiccup mode during output short-circuit protection (MODE bit5).
+    pub async fn set_hiccup_enabled(&mut self, enabled: bool) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        mode.set(ModeBits::HICCUP, enabled);
         self.write_reg(addr::MODE, mode.bits()).await
     }
 
-    pub async fn write_reg(&mut self, reg: u8, value: u8) -> Result<(), Error<I2C::Error>> {
-        self.i2c
-            .write(self.address, &[reg, value])
-            .await
-            .map_err(Error::I2c)
+    /// Return whether hiccup mode is enabled (MODE bit5, set after reset).
+    pub async fn is_hiccup_enabled(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mode = self.read_mode().await?;
+        Ok(mode.contains(ModeBits::HICCUP))
     }
 
-    pub async fn read_reg(&mut self, reg: u8) -> Result<u8, Error<I2C::Error>> {
-        let mut buf = [0u8; 1];
-        self.i2c
-            .write_read(self.address, &[reg], &mut buf)
-            .await
-            .map_err(Error::I2c)?;
-        Ok(buf[0])
+    /// Enable or disable the output discharge current sink (MODE bit4).
+    ///
+    /// When enabled, VOUT is pulled to ground by an internal ~100 mA sink while the device is
+    /// in shutdown, so enable it before dropping OE for a controlled power-down.
+    pub async fn set_output_discharge(&mut self, enabled: bool) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        mode.set(ModeBits::DISCHG, enabled);
+        self.write_reg(addr::MODE, mode.bits()).await
     }
 
-    pub async fn update_reg(
-        &mut self,
-        reg: u8,
-        mask: u8,
-        value: u8,
-    ) -> Result<(), Error<I2C::Error>> {
-        let cur = self.read_reg(reg).await?;
-        let new = (cur & !mask) | (value & mask);
-        self.write_reg(reg, new).await
+    /// Return whether output discharge is enabled (MODE bit4).
+    pub async fn get_output_discharge(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mode = self.read_mode().await?;
+        Ok(mode.contains(ModeBits::DISCHG))
     }
 
-    /// Configure the light-load operating mode (PFM/FPWM) via the MODE register.
+    /// Enable or disable switching-frequency doubling in buck-boost mode (MODE bit6).
+    pub async fn set_frequency_doubling(&mut self, enabled: bool) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        mode.set(ModeBits::FSWDBL, enabled);
+        self.write_reg(addr::MODE, mode.bits()).await
+    }
+
+    /// Return whether switching-frequency doubling is enabled (MODE bit6).
+    pub async fn get_frequency_doubling(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mode = self.read_mode().await?;
+        Ok(mode.contains(ModeBits::FSWDBL))
+    }
+
+    /// Read and decode the full MODE register.
+    pub async fn read_mode_config(&mut self) -> Result<ModeConfig, Error<I2C::Error>> {
+        let mode = self.read_mode().await?;
+        Ok(ModeConfig::from_bits(mode))
+    }
+
+    /// Write the full MODE register from a decoded configuration (no read-modify-write).
+    pub async fn write_mode_config(&mut self, cfg: &ModeConfig) -> Result<(), Error<I2C::Error>> {
+        self.write_reg(addr::MODE, cfg.to_bits().bits()).await
+    }
+
+    /// Configure the output current limit for an explicit sense resistor (mΩ).
     ///
-    /// See the blocking `set_light_load_mode` for the datasheet semantics.
-    pub async fn set_light_load_mode(
+    /// Returns `Error::InvalidConfig` for a zero sense resistor. The setting is truncated to
+    /// the next lower 0.5 mV step and clamped to 63.5 mV.
+    pub async fn set_ilim_from_sense(
         &mut self,
-        override_sel: LightLoadOverride,
-        mode: LightLoadMode,
+        limit_ma: u16,
+        rsense_mohm: u16,
+        enable: bool,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = ModeBits::from_bits_truncate(self.read_reg(addr::MODE).await?);
+        if rsense_mohm == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        let mut val = ilim_ma_to_code_for_sense(limit_ma, rsense_mohm);
+        if enable {
+            val |= IoutLimitBits::EN.bits();
+        }
+        self.write_reg(addr::IOUT_LIMIT, val).await
+    }
 
-        match override_sel {
-            LightLoadOverride::FromPreset => bits.remove(ModeBits::MODE),
-            LightLoadOverride::FromRegister => bits.insert(ModeBits::MODE),
+    /// Read the output current limit (mA, enable flag) for an explicit sense resistor (mΩ).
+    pub async fn get_ilim_from_sense(
+        &mut self,
+        rsense_mohm: u16,
+    ) -> Result<(u16, bool), Error<I2C::Error>> {
+        if rsense_mohm == 0 {
+            return Err(Error::InvalidConfig);
         }
+        let val = self.read_reg(addr::IOUT_LIMIT).await?;
+        let enable = (val & IoutLimitBits::EN.bits()) != 0;
+        Ok((
+            code_to_ilim_ma_for_sense(val & ILIM_MAX_CODE, rsense_mohm),
+            enable,
+        ))
+    }
 
-        match mode {
-            LightLoadMode::Pfm => bits.remove(ModeBits::PFM),
-            LightLoadMode::Pwm => bits.insert(ModeBits::PFM),
+    /// Write the raw 10-bit REF DAC code (REF0 low byte, REF1 bits 9:8).
+    ///
+    /// Bits above bit 9 are masked off. With the cached feedback source internal, codes whose
+    /// VOUT at the cached ratio falls outside the datasheet output range (`VOUT_MIN_MV`..=
+    /// `VOUT_SPEC_MAX_MV`, e.g. REF below 0078h on the 0.2256 ratio) return
+    /// `Error::OutOfRange`. External feedback accepts the full code range, since the divider
+    /// sets the output. See `set_vout_code_unchecked` to skip the check.
+    pub async fn set_vout_code(&mut self, code: u16) -> Result<(), Error<I2C::Error>> {
+        let code = code & REF_CODE_MAX;
+        if matches!(self.feedback_source, FeedbackSource::Internal) {
+            let mv = code_to_vout_mv_for_ratio(code, self.feedback_ratio);
+            if !(VOUT_MIN_MV..=VOUT_SPEC_MAX_MV).contains(&mv) {
+                return Err(Error::OutOfRange);
+            }
         }
+        self.set_vout_code_unchecked(code).await
+    }
 
-        self.write_reg(addr::MODE, bits.bits()).await
+    /// `set_vout_code` without the output range check.
+    ///
+    /// Useful with external feedback, where the millivolt conversions do not apply. REF1 is
+    /// read first so its bits outside `VoutRefBits` are written back unchanged; REF0 and REF1
+    /// then go out in one burst (the DAC loads on the REF1 write).
+    pub async fn set_vout_code_unchecked(&mut self, code: u16) -> Result<(), Error<I2C::Error>> {
+        let bytes = ref_bytes(code, self.read_reg(addr::REF1).await?);
+        self.write_regs(addr::REF0, &bytes).await
     }
 
-    /// Configure MODE register control source + the trio it gates (VCC/I2CADD/PFM).
+    /// Read back the raw 10-bit REF DAC code.
+    pub async fn get_vout_code(&mut self) -> Result<u16, Error<I2C::Error>> {
+        let mut buf = [0u8; 2];
+        self.read_regs(addr::REF0, &mut buf).await?;
+        Ok(u16::from_le_bytes(buf) & REF_CODE_MAX)
+    }
+
+    /// Set the output voltage (mV) of an external-feedback design with the given divider.
     ///
-    /// See the blocking `set_mode_control` for details.
-    pub async fn set_mode_control(
+    /// Picks the REF code via `ref_code_for_external_vout` and writes it with
+    /// `set_vout_code_unchecked`.
+    /// FB must already select the external divider (`set_feedback(FeedbackSource::External, ..)`).
+    /// Returns `Error::InvalidConfig` for a zero `rbottom_ohm`.
+    pub async fn set_external_vout_mv(
         &mut self,
-        override_sel: LightLoadOverride,
-        vcc_source: VccSource,
-        address: I2cAddress,
-        light_load_mode: LightLoadMode,
+        target_mv: u16,
+        rtop_ohm: u32,
+        rbottom_ohm: u32,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = ModeBits::from_bits_truncate(self.read_reg(addr::MODE).await?);
+        if rbottom_ohm == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        self.set_vout_code_unchecked(ref_code_for_external_vout(target_mv, rtop_ohm, rbottom_ohm))
+            .await
+    }
 
-        match override_sel {
-            LightLoadOverride::FromPreset => bits.remove(ModeBits::MODE),
-            LightLoadOverride::FromRegister => bits.insert(ModeBits::MODE),
+    /// Walk the output setpoint from `from_mv` to `to_mv` in `step_mv` increments.
+    ///
+    /// Each intermediate setpoint is written with `set_vout_mv`, followed by `step_delay_us` of
+    /// `delay`; the last step is shortened so the ramp ends exactly at `to_mv`. Works for rising
+    /// and falling ramps. Returns `Error::InvalidConfig` for a zero `step_mv`.
+    pub async fn ramp_vout_mv<D: DelayNs>(
+        &mut self,
+        from_mv: u16,
+        to_mv: u16,
+        step_mv: u16,
+        step_delay_us: u32,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        if step_mv == 0 {
+            return Err(Error::InvalidConfig);
         }
+        let mut mv = from_mv;
+        loop {
+            self.set_vout_mv(mv).await?;
+            if mv == to_mv {
+                return Ok(());
+            }
+            delay.delay_us(step_delay_us).await;
+            mv = if to_mv > mv {
+                mv.saturating_add(step_mv).min(to_mv)
+            } else {
+                mv.saturating_sub(step_mv).max(to_mv)
+            };
+        }
+    }
 
-        match vcc_source {
-            VccSource::Internal => bits.remove(ModeBits::VCC_EXT),
-            VccSource::External5v => bits.insert(ModeBits::VCC_EXT),
+    /// Read only the fault flags from STATUS (the read also clears them on the chip).
+    pub async fn read_fault_status(&mut self) -> Result<FaultStatus, Error<I2C::Error>> {
+        Ok(self.read_status().await?.1)
+    }
+
+    /// Clear the fault flags and confirm they stay clear, polling up to `retries` more times.
+    ///
+    /// Each attempt calls `clear_faults` and re-reads STATUS; between attempts `delay` waits
+    /// `poll_delay_us`. Returns `Error::FaultPersists` if a fault is still reported after the
+    /// last attempt.
+    pub async fn wait_for_fault_clear<D: DelayNs>(
+        &mut self,
+        retries: u8,
+        poll_delay_us: u32,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        for attempt in 0..=retries {
+            if attempt > 0 {
+                delay.delay_us(poll_delay_us).await;
+            }
+            self.clear_faults().await?;
+            if self.read_fault_status().await?.is_clear() {
+                return Ok(());
+            }
         }
+        Err(Error::FaultPersists)
+    }
 
-        match address {
-            I2cAddress::Addr0x74 => bits.remove(ModeBits::I2CADD),
-            I2cAddress::Addr0x75 => bits.insert(ModeBits::I2CADD),
+    /// Check whether a device acknowledges at the configured address.
+    ///
+    /// Performs a one-byte read of MODE. A NACK maps to `Ok(false)`; any other bus error is
+    /// returned as `Error::I2c`.
+    pub async fn probe(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mut buf = [0u8; 1];
+        match self
+            .i2c
+            .write_read(self.address, &[addr::MODE], &mut buf)
+            .await
+        {
+            Ok(()) => Ok(true),
+            Err(e) if is_nack(&e) => Ok(false),
+            Err(e) => Err(Error::I2c(e)),
         }
+    }
 
-        match light_load_mode {
-            LightLoadMode::Pfm => bits.remove(ModeBits::PFM),
-            LightLoadMode::Pwm => bits.insert(ModeBits::PFM),
+    /// Probe `candidates` in order and bind to the first address that acknowledges.
+    ///
+    /// Returns `Error::NoDevice` when every candidate NACKs; other bus errors abort the scan.
+    /// On failure the bus is handed back with the error so it can still be used.
+    pub async fn detect(i2c: I2C, candidates: &[u8]) -> Result<Self, (I2C, Error<I2C::Error>)> {
+        let mut dev = Self::new(i2c);
+        for &address in candidates {
+            dev.set_address(address);
+            match dev.probe().await {
+                Ok(true) => return Ok(dev),
+                Ok(false) => {}
+                Err(e) => return Err((dev.free(), e)),
+            }
         }
+        Err((dev.free(), Error::NoDevice))
+    }
 
-        self.write_reg(addr::MODE, bits.bits()).await
+    /// `detect` over `CANDIDATE_I2C_ADDRESSES` (0x74, then 0x75).
+    pub async fn detect_default(i2c: I2C) -> Result<Self, (I2C, Error<I2C::Error>)> {
+        Self::detect(i2c, &CANDIDATE_I2C_ADDRESSES).await
     }
 
-    pub async fn write_regs(
+    /// Select the external divider on FB/INT (VOUT_FS = 80h); the cached ratio is kept.
+    pub async fn set_external_feedback(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.set_feedback(FeedbackSource::External, self.feedback_ratio)
+            .await
+    }
+
+    /// Select internal feedback with `ratio`, cached for `set_vout_mv`.
+    pub async fn set_internal_feedback(
         &mut self,
-        start_reg: u8,
-        data: &[u8],
+        ratio: InternalFeedbackRatio,
     ) -> Result<(), Error<I2C::Error>> {
+        self.set_feedback(FeedbackSource::Internal, ratio).await
+    }
+
+    /// Read back the feedback source and internal ratio from VOUT_FS.
+    ///
+    /// Also refreshes the cached ratio used by `set_vout_mv`, e.g. after the chip was reset.
+    pub async fn get_feedback(
+        &mut self,
+    ) -> Result<(FeedbackSource, InternalFeedbackRatio), Error<I2C::Error>> {
+        let bits = VoutFsBits::from_bits_truncate(self.read_reg(addr::VOUT_FS).await?);
+        let source = if bits.contains(VoutFsBits::FB_EXT) {
+            FeedbackSource::External
+        } else {
+            FeedbackSource::Internal
+        };
+        let ratio = match bits.bits() & (VoutFsBits::INTFB0 | VoutFsBits::INTFB1).bits() {
+            0b00 => InternalFeedbackRatio::R0_2256,
+            0b01 => InternalFeedbackRatio::R0_1128,
+            0b10 => InternalFeedbackRatio::R0_0752,
+            _ => InternalFeedbackRatio::R0_0564,
+        };
+        self.feedback_source = source;
+        self.feedback_ratio = ratio;
+        Ok((source, ratio))
+    }
+
+    /// Read back cable droop compensation and fault masks from CDC.
+    ///
+    /// Returns `(option, level, mask_sc, mask_ocp, mask_ovp)`, the same shape `set_cable_comp`
+    /// takes.
+    pub async fn get_cable_comp(&mut self) -> Result<CableCompSettings, Error<I2C::Error>> {
+        let bits = CdcBits::from_bits_truncate(self.read_reg(addr::CDC).await?);
+        let option = if bits.contains(CdcBits::CDC_OPT) {
+            CableCompOption::External
+        } else {
+            CableCompOption::Internal
+        };
+        let level = match bits.bits() & (CdcBits::CDC0 | CdcBits::CDC1 | CdcBits::CDC2).bits() {
+            0b000 => CableCompLevel::V0p0,
+            0b001 => CableCompLevel::V0p1,
+            0b010 => CableCompLevel::V0p2,
+            0b011 => CableCompLevel::V0p3,
+            0b100 => CableCompLevel::V0p4,
+            0b101 => CableCompLevel::V0p5,
+            0b110 => CableCompLevel::V0p6,
+            _ => CableCompLevel::V0p7,
+        };
+        Ok((
+            option,
+            level,
+            bits.contains(CdcBits::SC_MASK),
+            bits.contains(CdcBits::OCP_MASK),
+            bits.contains(CdcBits::OVP_MASK),
+        ))
+    }
+
+    /// Read back slew rate and OCP delay from VOUT_SR.
+    pub async fn get_vout_sr(&mut self) -> Result<(VoutSlewRate, OcpDelay), Error<I2C::Error>> {
+        let bits = VoutSrBits::from_bits_truncate(self.read_reg(addr::VOUT_SR).await?);
+        let slew = match bits.bits() & (VoutSrBits::SR0 | VoutSrBits::SR1).bits() {
+            0b00 => VoutSlewRate::Sr1p25MvPerUs,
+            0b01 => VoutSlewRate::Sr2p5MvPerUs,
+            0b10 => VoutSlewRate::Sr5MvPerUs,
+            _ => VoutSlewRate::Sr10MvPerUs,
+        };
+        let ocp_delay =
+            match (bits.bits() & (VoutSrBits::OCP_DELAY0 | VoutSrBits::OCP_DELAY1).bits()) >> 4 {
+                0b00 => OcpDelay::Us128,
+                0b01 => OcpDelay::Ms3_072,
+                0b10 => OcpDelay::Ms6_144,
+                _ => OcpDelay::Ms12_288,
+            };
+        Ok((slew, ocp_delay))
+    }
+
+    /// Read all eight registers (REF0..STATUS) in a single burst.
+    ///
+    /// Like any STATUS read, this clears the latched fault flags on the chip.
+    pub async fn read_all(&mut self) -> Result<RegisterSnapshot, Error<I2C::Error>> {
         let mut buf = [0u8; 8];
-        if data.len() + 1 > buf.len() {
-            return Err(Error::InvalidConfig);
-        }
-        buf[0] = start_reg;
-        buf[1..=data.len()].copy_from_slice(data);
-        self.i2c
-            .write(self.address, &buf[..=data.len()])
-            .await
-            .map_err(Error::I2c)
+        self.read_regs(addr::REF0, &mut buf).await?;
+        Ok(RegisterSnapshot::from_bytes(buf))
     }
 
-    pub async fn read_regs(
+    /// Write only the registers where `desired` differs from `current` (REF0..MODE).
+    ///
+    /// REF0 and REF1 go out together in one burst if either changed, so the DAC never latches
+    /// a half-updated code; every other differing register is a single-byte write in address
+    /// order, MODE last. STATUS is never written. The cached feedback ratio is not touched;
+    /// call `get_feedback` afterwards if VOUT_FS changed.
+    pub async fn apply_diff(
         &mut self,
-        start_reg: u8,
-        data: &mut [u8],
+        current: &RegisterSnapshot,
+        desired: &RegisterSnapshot,
     ) -> Result<(), Error<I2C::Error>> {
-        self.i2c
-            .write_read(self.address, &[start_reg], data)
-            .await
-            .map_err(Error::I2c)
+        let current = current.to_bytes();
+        let desired = desired.to_bytes();
+        if current[..2] != desired[..2] {
+            self.write_regs(addr::REF0, &desired[..2]).await?;
+        }
+        for reg in addr::IOUT_LIMIT..=addr::MODE {
+            let i = reg as usize;
+            if current[i] != desired[i] {
+                self.write_reg(reg, desired[i]).await?;
+            }
+        }
+        Ok(())
     }
 
-    pub async fn set_vout_mv(&mut self, mv: u16) -> Result<(), Error<I2C::Error>> {
-        let code = vout_mv_to_code(mv);
-        let bytes = code.to_le_bytes();
-        self.write_regs(addr::REF0, &bytes).await
+    /// Register image `init_with_config(cfg)` would leave behind, without writing anything.
+    ///
+    /// Reads REF0..MODE and applies `cfg` on top, using the driver's sense resistor and VREF
+    /// trim as `init_with_config` would. STATUS is not read, so latched faults are left intact
+    /// (`status` is 0 in the result); safe to run on a live converter.
+    pub async fn dry_run_config(
+        &mut self,
+        cfg: &Config,
+    ) -> Result<RegisterSnapshot, Error<I2C::Error>> {
+        Ok(self.config_diff(cfg).await?.target)
     }
 
-    pub async fn get_vout_mv(&mut self) -> Result<u16, Error<I2C::Error>> {
-        let mut buf = [0u8; 2];
+    /// `dry_run_config` together with the register contents it was derived from.
+    pub async fn config_diff(&mut self, cfg: &Config) -> Result<ConfigDiff, Error<I2C::Error>> {
+        let mut buf = [0u8; 8];
+        self.read_regs(addr::REF0, &mut buf[..7]).await?;
+        let current = RegisterSnapshot::from_bytes(buf);
+        Ok(ConfigDiff {
+            current,
+            target: self.config_image(cfg, &current),
+        })
+    }
+
+    /// True when REF0..MODE all hold their datasheet reset values (`RESET_VALUES`).
+    ///
+    /// A driver that programmed the device earlier can use this to detect an unexpected
+    /// power cycle. STATUS is not read, so latched faults are left intact.
+    pub async fn is_at_reset(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mut buf = [0u8; 7];
         self.read_regs(addr::REF0, &mut buf).await?;
-        let code = u16::from_le_bytes(buf);
-        Ok(code_to_vout_mv(code))
+        Ok(buf == RESET_VALUES)
     }
 
-    pub async fn set_ilim_ma(&mut self, ma: u16, enable: bool) -> Result<(), Error<I2C::Error>> {
-        let code = ilim_ma_to_code(ma) & 0x7F;
-        let mut val = code;
-        if enable {
-            val |= IoutLimitBits::EN.bits();
-        }
-        self.write_reg(addr::IOUT_LIMIT, val).await
+    /// Restore the datasheet reset values of REF0..MODE without power-cycling. STATUS is left
+    /// alone.
+    ///
+    /// OE is cleared first so the output is off while the setpoint changes; MODE is written last
+    /// because resetting it hands VCC/I2CADD/PFM back to the MODE pin, which may move the device
+    /// to a different address. The cached feedback ratio returns to 0.0564.
+    pub async fn reset_to_defaults(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.disable_output().await?;
+        self.write_regs(
+            addr::REF0,
+            &[
+                RESET_REF0,
+                RESET_REF1,
+                RESET_IOUT_LIMIT,
+                RESET_VOUT_SR,
+                RESET_VOUT_FS,
+                RESET_CDC,
+            ],
+        )
+        .await?;
+        self.feedback_source = FeedbackSource::Internal;
+        self.feedback_ratio = InternalFeedbackRatio::R0_0564;
+        self.write_reg(addr::MODE, RESET_MODE).await
     }
 
-    pub async fn get_ilim_ma(&mut self) -> Result<(u16, bool), Error<I2C::Error>> {
-        let val = self.read_reg(addr::IOUT_LIMIT).await?;
-        let enable = (val & IoutLimitBits::EN.bits()) != 0;
-        let code = val & 0x7F;
-        Ok((code_to_ilim_ma(code), enable))
+    /// Program REF0..CDC from a [`Config`] in a single six-byte burst.
+    ///
+    /// Fewer transactions than `init_with_config` and no intermediate mix of old and new
+    /// settings. REF1 is read first so its bits outside the DAC field are written back
+    /// unchanged. The burst lands in IOUT_LIMIT before CDC, so CDC is written beforehand with
+    /// OCP_MASK cleared and the burst keeps it cleared; light-load mode and OE follow as
+    /// separate MODE writes, and OCP_MASK is set last as in `init_with_config`.
+    pub async fn write_config_burst(&mut self, cfg: &Config) -> Result<(), Error<I2C::Error>> {
+        let ref1 = self.read_reg(addr::REF1).await?;
+        let mut data = self.config_bytes(cfg, ref1);
+        let cdc = data[5];
+        data[5] &= !CdcBits::OCP_MASK.bits();
+        self.write_reg(addr::CDC, data[5]).await?;
+        self.write_regs(addr::REF0, &data).await?;
+        self.feedback_source = cfg.feedback_source;
+        self.feedback_ratio = cfg.feedback_ratio;
+        if let Some(mode) = cfg.light_load_mode {
+            self.set_light_load_mode(LightLoadOverride::FromRegister, mode)
+                .await?;
+        }
+        if cfg.enable_output {
+            self.enable_output().await?;
+        }
+        if cdc != data[5] {
+            self.write_reg(addr::CDC, cdc).await?;
+        }
+        Ok(())
     }
 
-    pub async fn set_vout_sr(
+    /// Set the output voltage and wait until the slew-limited transition should be complete.
+    ///
+    /// The previous setpoint is read back from REF, so the wait covers the actual step size.
+    /// `slew` must match the rate programmed in VOUT_SR; it is only used for the timing.
+    pub async fn change_vout_and_wait<D: DelayNs>(
         &mut self,
+        mv: u16,
         slew: VoutSlewRate,
-        ocp_delay: OcpDelay,
+        delay: &mut D,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = VoutSrBits::empty();
-        bits |= match slew {
-            VoutSlewRate::Sr1p25MvPerUs => VoutSrBits::empty(),
-            VoutSlewRate::Sr2p5MvPerUs => VoutSrBits::SR0,
-            VoutSlewRate::Sr5MvPerUs => VoutSrBits::SR1,
-            VoutSlewRate::Sr10MvPerUs => VoutSrBits::SR0 | VoutSrBits::SR1,
-        };
-        bits |= match ocp_delay {
-            OcpDelay::Us128 => VoutSrBits::empty(),
-            OcpDelay::Ms3_072 => VoutSrBits::OCP_DELAY0,
-            OcpDelay::Ms6_144 => VoutSrBits::OCP_DELAY1,
-            OcpDelay::Ms12_288 => VoutSrBits::OCP_DELAY0 | VoutSrBits::OCP_DELAY1,
-        };
-        self.write_reg(addr::VOUT_SR, bits.bits()).await
+        let ratio = self.feedback_ratio;
+        let from_mv = code_to_vout_mv_for_ratio(self.get_vout_code().await?, ratio);
+        let to_mv = code_to_vout_mv_for_ratio(self.trimmed_vout_code(mv, ratio), ratio);
+        self.set_vout_mv(mv).await?;
+        delay
+            .delay_us(self.vout_settle_time_us(from_mv.abs_diff(to_mv), slew))
+            .await;
+        Ok(())
     }
 
-    pub async fn set_feedback(
+    /// Move the device to `address` through the MODE I2CADD bit and retarget the driver with it.
+    ///
+    /// The MODE write goes to the current address; the chip answers on the new address only
+    /// after that write is acknowledged, so the stored address is switched afterwards. MODE bit0
+    /// is set as well so the register takes effect, which also applies the VCC and PFM register
+    /// bits in place of the MODE-pin preset.
+    pub async fn set_software_address(
         &mut self,
-        source: FeedbackSource,
-        ratio: InternalFeedbackRatio,
+        address: I2cAddress,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = VoutFsBits::empty();
-        if matches!(source, FeedbackSource::External) {
-            bits |= VoutFsBits::FB_EXT;
-        }
-        bits |= match ratio {
-            InternalFeedbackRatio::R0_2256 => VoutFsBits::empty(),
-            InternalFeedbackRatio::R0_1128 => VoutFsBits::INTFB0,
-            InternalFeedbackRatio::R0_0752 => VoutFsBits::INTFB1,
-            InternalFeedbackRatio::R0_0564 => VoutFsBits::INTFB0 | VoutFsBits::INTFB1,
+        let mut mode = self.read_mode().await?;
+        mode.set(ModeBits::I2CADD, matches!(address, I2cAddress::Addr0x75));
+        mode.insert(ModeBits::MODE);
+        self.write_reg(addr::MODE, mode.bits()).await?;
+        self.address = address.addr();
+        Ok(())
+    }
+
+    /// Apply a MODE-pin preset's VCC source, I2C address and light-load mode through the
+    /// MODE register, e.g. on a board strapped with the 0 Ω preset.
+    ///
+    /// Sets MODE bit0 so the register bits take effect, then retargets the driver to the
+    /// preset's address (the write itself goes to the current one, as in
+    /// `set_software_address`). The other MODE bits are kept.
+    pub async fn apply_preset_overrides(
+        &mut self,
+        preset: &ModePreset,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.set_mode_control(
+            LightLoadOverride::FromRegister,
+            preset.vcc_source,
+            preset.address,
+            preset.light_load_mode,
+        )
+        .await?;
+        self.address = preset.address.addr();
+        Ok(())
+    }
+
+    /// Bring the converter up in the recommended order.
+    ///
+    /// Waits `startup_us` after EN for the internal startup, programs `cfg` with OE still off,
+    /// then sets OE and reads MODE back. Returns `Error::Mismatch` if the readback differs from
+    /// the value written. OCP_MASK stays clear until OE is set, as in `init_with_config`. The
+    /// output is enabled regardless of `cfg.enable_output`.
+    pub async fn start<D: DelayNs>(
+        &mut self,
+        startup_us: u32,
+        delay: &mut D,
+        cfg: &Config,
+    ) -> Result<(), Error<I2C::Error>> {
+        delay.delay_us(startup_us).await;
+        let staged = Config {
+            enable_output: false,
+            mask_ocp: false,
+            ..*cfg
         };
-        self.write_reg(addr::VOUT_FS, bits.bits()).await
+        self.init_with_config(&staged).await?;
+        let mut mode = self.read_mode().await?;
+        mode.insert(ModeBits::OE);
+        self.write_reg(addr::MODE, mode.bits()).await?;
+        let actual = self.read_reg(addr::MODE).await?;
+        if actual != mode.bits() {
+            return Err(Error::Mismatch {
+                reg: addr::MODE,
+                expected: mode.bits(),
+                actual,
+            });
+        }
+        if cfg.mask_ocp {
+            self.set_cable_comp(
+                cfg.cable_comp_option,
+                cfg.cable_comp_level,
+                cfg.mask_sc,
+                true,
+                cfg.mask_ovp,
+            )
+            .await?;
+        }
+        Ok(())
     }
 
-    pub async fn set_cable_comp(
+    /// Read REF0..MODE back and compare against what `cfg` programs.
+    ///
+    /// REF0..CDC must match exactly. In MODE only the bits `cfg` controls are checked: OE when
+    /// `enable_output` is set, and MODE bit0 plus PFM when `light_load_mode` is set. The first
+    /// differing register is reported as `Error::Mismatch`.
+    pub async fn verify_config(&mut self, cfg: &Config) -> Result<(), Error<I2C::Error>> {
+        let mut actual = [0u8; 7];
+        self.read_regs(addr::REF0, &mut actual).await?;
+        let mut expected = [0u8; 7];
+        expected[..6].copy_from_slice(&self.config_bytes(cfg, actual[1]));
+        expected[6] = config_mode(cfg, actual[6]);
+        for (reg, (&expected, &actual)) in expected.iter().zip(actual.iter()).enumerate() {
+            if expected != actual {
+                return Err(Error::Mismatch {
+                    reg: reg as u8,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Update only the CDC fault indication mask bits (SC/OCP/OVP).
+    ///
+    /// CDC_OPT and the droop level CDC[2:0] are preserved via read-modify-write.
+    pub async fn set_fault_masks(
         &mut self,
-        option: CableCompOption,
-        level: CableCompLevel,
         mask_sc: bool,
         mask_ocp: bool,
         mask_ovp: bool,
     ) -> Result<(), Error<I2C::Error>> {
-        let mut bits = CdcBits::empty();
-        if mask_sc {
-            bits |= CdcBits::SC_MASK;
+        let mask = CdcBits::SC_MASK | CdcBits::OCP_MASK | CdcBits::OVP_MASK;
+        let bits = cdc_bits(
+            CableCompOption::Internal,
+            CableCompLevel::V0p0,
+            mask_sc,
+            mask_ocp,
+            mask_ovp,
+        );
+        self.update_reg(addr::CDC, mask.bits(), bits.bits()).await
+    }
+
+    /// Read back the CDC fault indication mask bits as `(mask_sc, mask_ocp, mask_ovp)`.
+    pub async fn get_fault_masks(&mut self) -> Result<(bool, bool, bool), Error<I2C::Error>> {
+        let bits = CdcBits::from_bits_truncate(self.read_reg(addr::CDC).await?);
+        Ok((
+            bits.contains(CdcBits::SC_MASK),
+            bits.contains(CdcBits::OCP_MASK),
+            bits.contains(CdcBits::OVP_MASK),
+        ))
+    }
+
+    /// Struct form of `set_fault_masks`.
+    pub async fn set_fault_mask_config(
+        &mut self,
+        masks: FaultMaskConfig,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.set_fault_masks(masks.short_circuit, masks.over_current, masks.over_voltage)
+            .await
+    }
+
+    /// Struct form of `get_fault_masks`.
+    pub async fn get_fault_mask_config(&mut self) -> Result<FaultMaskConfig, Error<I2C::Error>> {
+        let (short_circuit, over_current, over_voltage) = self.get_fault_masks().await?;
+        Ok(FaultMaskConfig {
+            short_circuit,
+            over_current,
+            over_voltage,
+        })
+    }
+
+    /// Update only the cable droop compensation approach (CDC_OPT) and level (CDC[2:0]).
+    ///
+    /// The SC/OCP/OVP mask bits are preserved via read-modify-write.
+    pub async fn set_cable_comp_level(
+        &mut self,
+        option: CableCompOption,
+        level: CableCompLevel,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mask = CdcBits::CDC_OPT | CdcBits::CDC0 | CdcBits::CDC1 | CdcBits::CDC2;
+        let bits = cdc_bits(option, level, false, false, false);
+        self.update_reg(addr::CDC, mask.bits(), bits.bits()).await
+    }
+
+    /// Read a register selected by the typed `Register` enum.
+    pub async fn read_register(&mut self, reg: Register) -> Result<u8, Error<I2C::Error>> {
+        self.read_reg(reg.addr()).await
+    }
+
+    /// Write a register selected by the typed `Register` enum.
+    pub async fn write_register(
+        &mut self,
+        reg: Register,
+        val: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_reg(reg.addr(), val).await
+    }
+
+    /// Service an FB/INT falling edge: read STATUS (clearing its latched flags) and return the
+    /// decoded faults.
+    ///
+    /// With internal feedback the FB/INT pin is an open-drain fault indicator that pulls low
+    /// on SCP/OCP/OVP (subject to the CDC mask bits); with external feedback it is the FB input
+    /// and never asserts. Wiring the pin to a GPIO and calling this from its handler or task is
+    /// left to the caller.
+    pub async fn on_interrupt(&mut self) -> Result<FaultStatus, Error<I2C::Error>> {
+        self.read_fault_status().await
+    }
+
+    /// Update only the VOUT slew rate (VOUT_SR SR[1:0]), preserving the OCP delay.
+    pub async fn set_slew_rate(&mut self, slew: VoutSlewRate) -> Result<(), Error<I2C::Error>> {
+        let mask = VoutSrBits::SR0 | VoutSrBits::SR1;
+        let bits = VoutSrConfig::new().slew(slew).build();
+        self.update_reg(addr::VOUT_SR, mask.bits(), bits.bits())
+            .await
+    }
+
+    /// Update only the overcurrent response delay (VOUT_SR OCP_DELAY[1:0]), preserving the
+    /// slew rate.
+    pub async fn set_ocp_delay(&mut self, ocp_delay: OcpDelay) -> Result<(), Error<I2C::Error>> {
+        let mask = VoutSrBits::OCP_DELAY0 | VoutSrBits::OCP_DELAY1;
+        let bits = VoutSrConfig::new().ocp_delay(ocp_delay).build();
+        self.update_reg(addr::VOUT_SR, mask.bits(), bits.bits())
+            .await
+    }
+
+    /// Set the output voltage, wait for the slew-limited transition, then read STATUS.
+    ///
+    /// Same timing as `change_vout_and_wait`; the returned status reflects the operating mode
+    /// after the step (e.g. buck vs. boost once a crossover has settled).
+    pub async fn set_vout_and_confirm<D: DelayNs>(
+        &mut self,
+        mv: u16,
+        slew: VoutSlewRate,
+        delay: &mut D,
+    ) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
+        self.change_vout_and_wait(mv, slew, delay).await?;
+        self.read_status().await
+    }
+
+    /// Sanity-check that the responder looks like a TPS55288.
+    ///
+    /// The chip has no ID register, so this reads REF0..MODE in one burst and requires every
+    /// reserved bit in `RESERVED_MASKS` to read as zero; any set bit yields
+    /// `Error::InvalidConfig`. STATUS is not read, so latched faults are left intact.
+    pub async fn check_identity(&mut self) -> Result<(), Error<I2C::Error>> {
+        let mut buf = [0u8; 7];
+        self.read_regs(addr::REF0, &mut buf).await?;
+        if buf
+            .iter()
+            .zip(RESERVED_MASKS.iter())
+            .any(|(val, mask)| val & mask != 0)
+        {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Read STATUS and turn the first latched fault into an error, for `?` in control loops.
+    ///
+    /// Faults are checked in STATUS bit order: short circuit, overcurrent, overvoltage. The
+    /// read clears the flags on the chip, as with `read_status`.
+    pub async fn check_faults(&mut self) -> Result<(), Error<I2C::Error>> {
+        match self.read_fault_status().await?.active().next() {
+            None => Ok(()),
+            Some(Fault::ShortCircuit) => Err(Error::ShortCircuit),
+            Some(Fault::OverCurrent) => Err(Error::OverCurrent),
+            Some(Fault::OverVoltage) => Err(Error::OverVoltage),
+        }
+    }
+
+    /// Write a burst of any length starting at `start_reg`, without the `write_regs` copy.
+    ///
+    /// The register byte and `data` go out as two adjacent write operations of one
+    /// `I2c::transaction`, which the bus emits as a single frame with no repeated start.
+    pub async fn write_regs_streaming(
+        &mut self,
+        start_reg: u8,
+        data: &[u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        self.i2c
+            .transaction(
+                self.address,
+                &mut [Operation::Write(&[start_reg]), Operation::Write(data)],
+            )
+            .await
+            .map_err(bus_error(start_reg, "write"))
+    }
+
+    /// Set VOUT (mV) and read STATUS within one `I2c::transaction`, so no other master can
+    /// get between the two on a shared bus.
+    ///
+    /// Adjacent writes in a transaction merge into a single frame, which would run the STATUS
+    /// address into IOUT_LIMIT. A one-byte read of the auto-incremented register (IOUT_LIMIT,
+    /// side-effect free) therefore separates the REF write from the STATUS pointer write.
+    /// REF1 is read beforehand, outside the transaction, to keep its bits outside the DAC field.
+    pub async fn atomic_set_and_read_status(
+        &mut self,
+        mv: u16,
+    ) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
+        let [ref0, ref1] = ref_bytes(
+            self.trimmed_vout_code(mv, self.feedback_ratio),
+            self.read_reg(addr::REF1).await?,
+        );
+        let mut separator = [0u8; 1];
+        let mut status = [0u8; 1];
+        self.i2c
+            .transaction(
+                self.address,
+                &mut [
+                    Operation::Write(&[addr::REF0, ref0, ref1]),
+                    Operation::Read(&mut separator),
+                    Operation::Write(&[addr::STATUS]),
+                    Operation::Read(&mut status),
+                ],
+            )
+            .await
+            .map_err(bus_error(addr::REF0, "write"))?;
+        let bits = StatusBits::from_bits_retain(status[0]);
+        Ok((decode_status(&bits), FaultStatus::from_bits(bits)))
+    }
+
+    /// Change the current limit (mA) while keeping the IOUT_LIMIT enable bit as it is.
+    pub async fn update_ilim_ma(&mut self, ma: u16) -> Result<(), Error<I2C::Error>> {
+        let code = ilim_ma_to_code_for_sense(ma, self.rsense_mohm);
+        self.update_reg(addr::IOUT_LIMIT, ILIM_MAX_CODE, code).await
+    }
+
+    /// Set or clear only the IOUT_LIMIT enable bit, keeping the programmed limit.
+    pub async fn enable_current_limit(&mut self, enable: bool) -> Result<(), Error<I2C::Error>> {
+        let en = IoutLimitBits::EN.bits();
+        self.update_reg(addr::IOUT_LIMIT, en, if enable { en } else { 0 })
+            .await
+    }
+
+    /// Turn the current limit off but keep the programmed code for a later
+    /// `enable_current_limit(true)`; same as `enable_current_limit(false)`.
+    pub async fn disable_current_limit_keep_setting(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.enable_current_limit(false).await
+    }
+
+    /// Read MODE and STATUS in one burst and summarize them as a [`StatusReport`].
+    ///
+    /// STATUS is read-to-clear, so the reported faults are consumed.
+    pub async fn read_report(&mut self) -> Result<StatusReport, Error<I2C::Error>> {
+        let mut buf = [0u8; 2];
+        self.read_regs(addr::MODE, &mut buf).await?;
+        Ok(StatusReport::from_registers(buf[0], buf[1], self.address))
+    }
+
+    /// Like `read_status`, but returns `Error::UnexpectedStatus` when STATUS[1:0] holds the
+    /// reserved code 0b11 while the output is enabled.
+    ///
+    /// 0b11 is also the STATUS reset value, so an idle device with OE clear reports it after
+    /// power-up (see `OperatingStatus::is_transitioning`); in that case MODE is read and the
+    /// reserved code is returned as `Ok`. Only with OE set, where the converter should be in a
+    /// regulation region, is it treated as an error.
+    pub async fn read_status_strict(
+        &mut self,
+    ) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
+        let bits = self.read_status_raw().await?;
+        let operating = decode_status(&bits);
+        if operating == OperatingStatus::Reserved && self.is_output_enabled().await? {
+            return Err(Error::UnexpectedStatus(bits.bits()));
         }
-        if mask_ocp {
-            bits |= CdcBits::OCP_MASK;
+        Ok((operating, FaultStatus::from_bits(bits)))
+    }
+
+    /// Controlled shutdown: arm output discharge, clear OE, then wait `discharge_ms`.
+    ///
+    /// DISCHG is set in its own write before OE drops so the output is pulled down rather than
+    /// left floating. After the wait DISCHG is restored to its previous state (cleared again
+    /// if it was off). The host may cut EN afterwards.
+    pub async fn shutdown<D: DelayNs>(
+        &mut self,
+        discharge_ms: u32,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut bits = ModeBits::from_bits_retain(self.read_reg(addr::MODE).await?);
+        let had_discharge = bits.contains(ModeBits::DISCHG);
+        bits.insert(ModeBits::DISCHG);
+        self.write_reg(addr::MODE, bits.bits()).await?;
+        bits.remove(ModeBits::OE);
+        self.write_reg(addr::MODE, bits.bits()).await?;
+        delay.delay_ms(discharge_ms).await;
+        if !had_discharge {
+            bits.remove(ModeBits::DISCHG);
+            self.write_reg(addr::MODE, bits.bits()).await?;
         }
-        if mask_ovp {
-            bits |= CdcBits::OVP_MASK;
+        Ok(())
+    }
+
+    /// Active current limit (mA), or `None` when the IOUT_LIMIT enable bit is clear.
+    pub async fn get_current_limit(&mut self) -> Result<Option<u16>, Error<I2C::Error>> {
+        let (ma, enabled) = self.get_ilim_ma().await?;
+        Ok(enabled.then_some(ma))
+    }
+
+    /// Read MODE as typed flags.
+    pub async fn read_mode(&mut self) -> Result<ModeBits, Error<I2C::Error>> {
+        Ok(ModeBits::from_bits_truncate(
+            self.read_reg(addr::MODE).await?,
+        ))
+    }
+
+    /// Write MODE from typed flags (no read-modify-write).
+    pub async fn write_mode(&mut self, mode: ModeBits) -> Result<(), Error<I2C::Error>> {
+        self.write_reg(addr::MODE, mode.bits()).await
+    }
+
+    /// Poll STATUS every `period_us` until a fault is latched, then return it.
+    ///
+    /// For async users this is a future an executor task can await instead of polling from
+    /// the main loop. It is cancellation-safe: each poll is a single write-read of STATUS, and
+    /// a fault is returned straight from the read that observed it, so dropping the future
+    /// (e.g. when it loses a `select`) only ever happens between transactions or during the
+    /// delay and never discards a read-to-clear fault.
+    pub async fn poll_faults<D: DelayNs>(
+        &mut self,
+        period_us: u32,
+        delay: &mut D,
+    ) -> Result<FaultStatus, Error<I2C::Error>> {
+        loop {
+            let faults = self.read_fault_status().await?;
+            if !faults.is_clear() {
+                return Ok(faults);
+            }
+            delay.delay_us(period_us).await;
         }
-        if matches!(option, CableCompOption::External) {
-            bits |= CdcBits::CDC_OPT;
+    }
+
+    /// `set_vout_mv` followed by a REF0/REF1 readback, guarding against a corrupted DAC write.
+    ///
+    /// The first register whose readback differs from the intended code is reported as
+    /// `Error::Mismatch`; the caller decides whether to retry or shut the output down.
+    pub async fn set_vout_mv_verified(&mut self, mv: u16) -> Result<(), Error<I2C::Error>> {
+        let intended = ref_bytes(
+            self.trimmed_vout_code(mv, self.feedback_ratio),
+            self.read_reg(addr::REF1).await?,
+        );
+        self.write_regs(addr::REF0, &intended).await?;
+        let mut actual = [0u8; 2];
+        self.read_regs(addr::REF0, &mut actual).await?;
+        for (reg, (&expected, &actual)) in intended.iter().zip(actual.iter()).enumerate() {
+            if expected != actual {
+                return Err(Error::Mismatch {
+                    reg: addr::REF0 + reg as u8,
+                    expected,
+                    actual,
+                });
+            }
         }
-        let level_bits = match level {
-            CableCompLevel::V0p0 => CdcBits::empty(),
-            CableCompLevel::V0p1 => CdcBits::CDC0,
-            CableCompLevel::V0p2 => CdcBits::CDC1,
-            CableCompLevel::V0p3 => CdcBits::CDC0 | CdcBits::CDC1,
-            CableCompLevel::V0p4 => CdcBits::CDC2,
-            CableCompLevel::V0p5 => CdcBits::CDC2 | CdcBits::CDC0,
-            CableCompLevel::V0p6 => CdcBits::CDC2 | CdcBits::CDC1,
-            CableCompLevel::V0p7 => CdcBits::CDC2 | CdcBits::CDC1 | CdcBits::CDC0,
-        };
-        bits |= level_bits;
-        self.write_reg(addr::CDC, bits.bits()).await
+        Ok(())
     }
 
-    pub async fn read_status_raw(&mut self) -> Result<StatusBits, Error<I2C::Error>> {
-        let val = self.read_reg(addr::STATUS).await?;
-        Ok(StatusBits::from_bits_truncate(val))
+    /// Change the feedback path with the output off: clear OE, write VOUT_FS, program
+    /// `ref_code` for the new path, wait `settle_us`, then restore OE.
+    ///
+    /// Switching between internal and external feedback on a live output causes a transient,
+    /// since the same REF code means a different VOUT on each path. OE is only set again if it
+    /// was set on entry. `ref_code` goes through `set_vout_code`, so out-of-range codes for an
+    /// internal ratio are rejected (with OE left off).
+    pub async fn switch_feedback<D: DelayNs>(
+        &mut self,
+        source: FeedbackSource,
+        ratio: InternalFeedbackRatio,
+        ref_code: u16,
+        settle_us: u32,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut mode = self.read_mode().await?;
+        let was_enabled = mode.contains(ModeBits::OE);
+        if was_enabled {
+            mode.remove(ModeBits::OE);
+            self.write_reg(addr::MODE, mode.bits()).await?;
+        }
+        self.set_feedback(source, ratio).await?;
+        self.set_vout_code(ref_code).await?;
+        delay.delay_us(settle_us).await;
+        if was_enabled {
+            mode.insert(ModeBits::OE);
+            self.write_reg(addr::MODE, mode.bits()).await?;
+        }
+        Ok(())
     }
+}
 
-    pub async fn read_status(
+#[cfg(feature = "async")]
+impl<I2C> Tps55288<I2C>
+where
+    I2C: I2c,
+{
+    /// `read_status`, raced against a `timeout_us` delay.
+    ///
+    /// Returns `Error::Timeout` if the delay finishes first, so a hung bus cannot park the
+    /// calling task forever. The abandoned transfer is dropped mid-flight; the bus may need
+    /// recovery (e.g. clocking SCL) before the next transaction.
+    pub async fn read_status_timeout<D: DelayNs>(
         &mut self,
+        timeout_us: u32,
+        delay: &mut D,
     ) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
-        let bits = self.read_status_raw().await?;
-        let mode_bits = decode_status_mode(&bits);
-        let operating = match mode_bits {
-            0b00 => OperatingStatus::Boost,
-            0b01 => OperatingStatus::Buck,
-            0b10 => OperatingStatus::BuckBoost,
-            _ => OperatingStatus::Reserved,
-        };
-        let faults = FaultStatus {
-            short_circuit: bits.contains(StatusBits::SCP),
-            over_current: bits.contains(StatusBits::OCP),
-            over_voltage: bits.contains(StatusBits::OVP),
-        };
-        Ok((operating, faults))
+        use core::future::{Future, poll_fn};
+        use core::pin::pin;
+        use core::task::Poll;
+
+        let mut read = pin!(self.read_status());
+        let mut timer = pin!(delay.delay_us(timeout_us));
+        poll_fn(|cx| {
+            if let Poll::Ready(res) = read.as_mut().poll(cx) {
+                return Poll::Ready(res);
+            }
+            if timer.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Err(Error::Timeout));
+            }
+            Poll::Pending
+        })
+        .await
     }
 }
@@ -9,6 +9,24 @@ pub enum Error<I2cError> {
     OutOfRange,
     /// Unsupported/invalid configuration for current mode.
     InvalidConfig,
+    /// Fault flags were still set after clearing and polling STATUS.
+    FaultPersists,
+    /// No device acknowledged at any candidate address.
+    NoDevice,
+    /// Register readback differs from the value that was written.
+    Mismatch { reg: u8, expected: u8, actual: u8 },
+    /// A burst did not fit the driver's fixed transfer buffer (sizes in bytes).
+    BufferTooSmall { needed: usize, capacity: usize },
+    /// STATUS held the reserved operating-mode code with OE set (raw STATUS byte attached).
+    UnexpectedStatus(u8),
+    /// STATUS reported an output short circuit (SCP).
+    ShortCircuit,
+    /// STATUS reported an overcurrent condition (OCP).
+    OverCurrent,
+    /// STATUS reported an output overvoltage (OVP).
+    OverVoltage,
+    /// The bus did not complete a transaction before the caller's deadline.
+    Timeout,
 }
 
 impl<I2cError: core::fmt::Debug> core::fmt::Display for Error<I2cError> {
@@ -17,6 +35,29 @@ impl<I2cError: core::fmt::Debug> core::fmt::Display for Error<I2cError> {
             Error::I2c(e) => write!(f, "I2C error: {:?}", e),
             Error::OutOfRange => write!(f, "parameter out of range"),
             Error::InvalidConfig => write!(f, "invalid configuration for current mode"),
+            Error::FaultPersists => write!(f, "fault persists after clearing"),
+            Error::NoDevice => write!(f, "no device acknowledged"),
+            Error::Mismatch {
+                reg,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "register 0x{:02X} reads 0x{:02X}, expected 0x{:02X}",
+                reg, actual, expected
+            ),
+            Error::BufferTooSmall { needed, capacity } => write!(
+                f,
+                "transfer needs {} bytes, buffer holds {}",
+                needed, capacity
+            ),
+            Error::UnexpectedStatus(raw) => {
+                write!(f, "unexpected STATUS 0x{:02X} (reserved mode)", raw)
+            }
+            Error::ShortCircuit => write!(f, "output short circuit"),
+            Error::OverCurrent => write!(f, "output overcurrent"),
+            Error::OverVoltage => write!(f, "output overvoltage"),
+            Error::Timeout => write!(f, "I2C transaction timed out"),
         }
     }
 }
@@ -6,11 +6,21 @@
 
 #![no_std]
 
+pub mod config;
 pub mod data_types;
 pub mod driver;
 pub mod error;
 pub mod registers;
+#[cfg(feature = "embassy")]
+pub mod shared;
+#[cfg(feature = "sim")]
+pub mod sim;
+pub mod stepper;
+pub mod watcher;
 
-pub use driver::Tps55288;
+pub use config::Config;
+pub use driver::{Tps55288, Tps55288Builder};
 pub use error::Error;
 pub use registers::DEFAULT_I2C_ADDRESS;
+pub use stepper::StableStepper;
+pub use watcher::StatusWatcher;
@@ -1,17 +1,21 @@
 //! Register map and constants for TPS55288.
 //! Values and limits are copied from the datasheet; conversion helpers will be added later.
 
+use crate::data_types::{InternalFeedbackRatio, OcpDelay, OperatingStatus, VoutSlewRate};
+
 /// Default I2C address when MODE pin selects internal VCC + PWM (datasheet table, 0 Ω option).
 pub const DEFAULT_I2C_ADDRESS: u8 = 0x74;
 /// Alternate I2C address selected via MODE pin preset.
 pub const ALT_I2C_ADDRESS: u8 = 0x75;
+/// Addresses tried by `Tps55288::detect_default`, in order.
+pub const CANDIDATE_I2C_ADDRESSES: [u8; 2] = [DEFAULT_I2C_ADDRESS, ALT_I2C_ADDRESS];
 
 /// Register addresses (7-bit).
 pub mod addr {
     /// Reference voltage DAC (LSB 20 mV). Addresses 0x00 and 0x01 hold the 10-bit value.
     pub const REF0: u8 = 0x00;
     pub const REF1: u8 = 0x01;
-    /// Output current limit (50 mA LSB, ~6.35 A max)
+    /// Output current limit (0.5 mV LSB across the sense resistor; 50 mA LSB with 10 mΩ)
     pub const IOUT_LIMIT: u8 = 0x02;
     /// VOUT slew-rate control
     pub const VOUT_SR: u8 = 0x03;
@@ -25,26 +29,129 @@ pub mod addr {
     pub const STATUS: u8 = 0x07;
 }
 
+/// Typed register selector mirroring the `addr::*` constants.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Register {
+    Ref0,
+    Ref1,
+    IoutLimit,
+    VoutSr,
+    VoutFs,
+    Cdc,
+    Mode,
+    Status,
+}
+
+impl Register {
+    /// Register address on the bus.
+    pub fn addr(self) -> u8 {
+        match self {
+            Register::Ref0 => addr::REF0,
+            Register::Ref1 => addr::REF1,
+            Register::IoutLimit => addr::IOUT_LIMIT,
+            Register::VoutSr => addr::VOUT_SR,
+            Register::VoutFs => addr::VOUT_FS,
+            Register::Cdc => addr::CDC,
+            Register::Mode => addr::MODE,
+            Register::Status => addr::STATUS,
+        }
+    }
+}
+
+/// Datasheet power-on reset values (register map table).
+pub const RESET_REF0: u8 = 0xD2;
+pub const RESET_REF1: u8 = 0x00;
+pub const RESET_IOUT_LIMIT: u8 = 0xE4;
+pub const RESET_VOUT_SR: u8 = 0x01;
+pub const RESET_VOUT_FS: u8 = 0x03;
+pub const RESET_CDC: u8 = 0xE0;
+pub const RESET_MODE: u8 = 0x20;
+pub const RESET_STATUS: u8 = 0x03;
+/// Reset values of REF0..MODE in address order (STATUS excluded, it is read-to-clear).
+pub const RESET_VALUES: [u8; 7] = [
+    RESET_REF0,
+    RESET_REF1,
+    RESET_IOUT_LIMIT,
+    RESET_VOUT_SR,
+    RESET_VOUT_FS,
+    RESET_CDC,
+    RESET_MODE,
+];
+
 /// Voltage DAC characteristics.
 pub const VOUT_LSB_MV: u16 = 20;
 pub const VOUT_MIN_MV: u16 = 800;
 // 10-bit DAC => 1024 steps from 0 to 1023 inclusive.
 pub const VOUT_MAX_MV: u16 = VOUT_MIN_MV + (1023 * VOUT_LSB_MV);
+/// Upper end of the datasheet output voltage range (0.8 V to 22 V).
+pub const VOUT_SPEC_MAX_MV: u16 = 22_000;
+/// Largest REF DAC code (10 bits across REF0/REF1).
+pub const REF_CODE_MAX: u16 = 0x03FF;
+/// Reference voltage at REF=0000h (µV).
+pub const VREF_MIN_UV: u32 = 45_000;
+/// Reference voltage step per REF DAC code (µV).
+pub const VREF_LSB_UV: u32 = 1_129;
 
 /// Output current limit DAC characteristics.
+///
+/// The chip regulates the ISP-ISN voltage (0.5 mV LSB, 63.5 mV max); the milliamp values
+/// below assume a 10 mΩ sense resistor. Use the `*_for_sense` helpers for other resistors.
 pub const ILIM_LSB_MA: u16 = 50;
 pub const ILIM_MAX_MA: u16 = 6_350;
+/// Current limit sense-voltage LSB (µV).
+pub const ILIM_LSB_UV: u32 = 500;
+/// Smallest IOUT_LIMIT code `set_ilim_ma_checked` accepts (2 mV sense voltage, the Vsns
+/// tolerance).
+///
+/// Lower settings, and code 0 in particular, hold the output in current limit at almost any load.
+pub const MIN_USEFUL_ILIM_CODE: u8 = 4;
+/// `MIN_USEFUL_ILIM_CODE` in milliamps with the default 10 mΩ sense resistor.
+pub const MIN_USEFUL_ILIM_MA: u16 = 200;
+/// Largest IOUT_LIMIT setting code (7 bits).
+pub const ILIM_MAX_CODE: u8 = 0x7F;
+/// Sense resistor (mΩ) the milliamp constants above assume; the driver's default.
+pub const DEFAULT_RSENSE_MOHM: u16 = 10;
+
+/// Output overvoltage protection thresholds on the VOUT pin (datasheet 7.3.15, min/typ/max).
+///
+/// OVP is fixed: no register scales it with the REF setpoint or feedback ratio. Once tripped,
+/// the high-side FETs stay off until VOUT falls `OVP_HYSTERESIS_MV` below the threshold.
+pub const OVP_MIN_MV: u16 = 22_500;
+pub const OVP_TYP_MV: u16 = 23_500;
+pub const OVP_MAX_MV: u16 = 24_500;
+/// OVP hysteresis (mV).
+pub const OVP_HYSTERESIS_MV: u16 = 1_000;
+
+/// VOUT (mV) at which output overvoltage protection typically trips (`OVP_TYP_MV`).
+///
+/// The TPS55288 has no programmable OVP margin, so this does not depend on the setpoint; see
+/// `ovp_may_trip` for the per-setpoint check.
+pub const fn ovp_trip_mv() -> u16 {
+    OVP_TYP_MV
+}
+
+/// True when a VOUT setpoint (mV) reaches `OVP_MIN_MV`, where OVP may trip on some parts.
+pub const fn ovp_may_trip(set_mv: u16) -> bool {
+    set_mv >= OVP_MIN_MV
+}
 
 /// Switching frequency range (set by RFSW pin; register FSWDBL in MODE can double frequency).
 pub const FSW_MIN_KHZ: u16 = 200;
 pub const FSW_MAX_KHZ: u16 = 2_200;
 
 bitflags::bitflags! {
+    /// REF1 register bits (0x01): the two DAC MSBs; bits 7-2 are reserved.
+    pub struct VoutRefBits: u8 {
+        const REF8 = 1 << 0;
+        const REF9 = 1 << 1;
+    }
+
     /// IOUT_LIMIT register bits (0x02).
     pub struct IoutLimitBits: u8 {
         /// Bit 7: Current limit enable (1 = enabled).
         const EN         = 1 << 7;
-        /// Bits 0-6: Current limit setting (steps of 0.5 mV across sense resistor).
+        /// Bits 0-6: Current limit setting (0.5 mV steps across the sense resistor).
         const SETTING_LSB = 1 << 0;
     }
 
@@ -110,31 +217,284 @@ bitflags::bitflags! {
     }
 }
 
+/// Reserved bits of REF0..MODE in address order; they read as zero on a TPS55288.
+pub const RESERVED_MASKS: [u8; 7] = [
+    0x00,        // REF0
+    0b1111_1100, // REF1
+    0x00,        // IOUT_LIMIT
+    0b1100_1100, // VOUT_SR
+    0b0111_1100, // VOUT_FS
+    0b0001_0000, // CDC
+    0x00,        // MODE
+];
+
+impl VoutSrBits {
+    /// Clear reserved bits 7-6 and 3-2, e.g. after `from_bits_retain` on a raw byte.
+    pub fn sanitize(self) -> Self {
+        Self::from_bits_truncate(self.bits())
+    }
+}
+
+/// STATUS bits 4-2, reserved in the datasheet (no power-good or thermal flag is defined).
+pub const STATUS_RESERVED_MASK: u8 = 0b0001_1100;
+
+impl StatusBits {
+    /// Reserved bits 4-2 as read, for observing undocumented behavior during bring-up.
+    ///
+    /// Only non-zero when the bits were built with `from_bits_retain`, as `read_status_raw`
+    /// does.
+    pub fn raw_reserved(&self) -> u8 {
+        self.bits() & STATUS_RESERVED_MASK
+    }
+}
+
+/// Fluent assembly of the VOUT_SR byte from a slew rate and OCP delay.
+///
+/// `new()` starts from the reset value (2.5 mV/µs, 128 µs).
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VoutSrConfig {
+    slew: VoutSlewRate,
+    ocp_delay: OcpDelay,
+}
+
+impl VoutSrConfig {
+    /// Start from the VOUT_SR reset value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the VOUT slew rate (SR[1:0]).
+    #[must_use]
+    pub fn slew(mut self, slew: VoutSlewRate) -> Self {
+        self.slew = slew;
+        self
+    }
+
+    /// Set the overcurrent response delay (OCP_DELAY[1:0]).
+    #[must_use]
+    pub fn ocp_delay(mut self, ocp_delay: OcpDelay) -> Self {
+        self.ocp_delay = ocp_delay;
+        self
+    }
+
+    /// Encode into VOUT_SR bits.
+    pub fn build(self) -> VoutSrBits {
+        let slew = match self.slew {
+            VoutSlewRate::Sr1p25MvPerUs => VoutSrBits::empty(),
+            VoutSlewRate::Sr2p5MvPerUs => VoutSrBits::SR0,
+            VoutSlewRate::Sr5MvPerUs => VoutSrBits::SR1,
+            VoutSlewRate::Sr10MvPerUs => VoutSrBits::SR0 | VoutSrBits::SR1,
+        };
+        let delay = match self.ocp_delay {
+            OcpDelay::Us128 => VoutSrBits::empty(),
+            OcpDelay::Ms3_072 => VoutSrBits::OCP_DELAY0,
+            OcpDelay::Ms6_144 => VoutSrBits::OCP_DELAY1,
+            OcpDelay::Ms12_288 => VoutSrBits::OCP_DELAY0 | VoutSrBits::OCP_DELAY1,
+        };
+        let bits = slew | delay;
+        debug_assert_eq!(bits.bits() & RESERVED_MASKS[addr::VOUT_SR as usize], 0);
+        bits
+    }
+}
+
+impl Default for VoutSrConfig {
+    fn default() -> Self {
+        Self {
+            slew: VoutSlewRate::Sr2p5MvPerUs,
+            ocp_delay: OcpDelay::Us128,
+        }
+    }
+}
+
+/// STATUS fault flags (SCP/OCP/OVP); the only bits `clear_faults` ever writes.
+pub const STATUS_FAULT_MASK: StatusBits = StatusBits::SCP
+    .union(StatusBits::OCP)
+    .union(StatusBits::OVP);
+
 /// Convert VOUT millivolts to DAC code (10-bit, 20 mV LSB). Clamps to datasheet limits.
-pub fn vout_mv_to_code(mv: u16) -> u16 {
-    let mv = mv.clamp(VOUT_MIN_MV, VOUT_MAX_MV);
+pub const fn vout_mv_to_code(mv: u16) -> u16 {
+    // `Ord::clamp`/`min` are not const; spell the bounds out.
+    let mv = if mv < VOUT_MIN_MV {
+        VOUT_MIN_MV
+    } else if mv > VOUT_MAX_MV {
+        VOUT_MAX_MV
+    } else {
+        mv
+    };
     let code = mv.saturating_sub(VOUT_MIN_MV) / VOUT_LSB_MV;
-    code.min(1023)
+    if code > 1023 { 1023 } else { code }
 }
 
 /// Convert DAC code to VOUT millivolts.
-pub fn code_to_vout_mv(code: u16) -> u16 {
-    let code = code.min(1023);
+pub const fn code_to_vout_mv(code: u16) -> u16 {
+    let code = if code > 1023 { 1023 } else { code };
     VOUT_MIN_MV + code * VOUT_LSB_MV
 }
 
+/// Like `vout_mv_to_code`, but rounds to the nearest 20 mV step instead of truncating
+/// (5010 mV -> 5020 mV; exact halves round up). Clamps to range.
+pub const fn vout_mv_to_code_rounded(mv: u16) -> u16 {
+    let mv = if mv < VOUT_MIN_MV {
+        VOUT_MIN_MV
+    } else if mv > VOUT_MAX_MV {
+        VOUT_MAX_MV
+    } else {
+        mv
+    };
+    let code = (mv - VOUT_MIN_MV + VOUT_LSB_MV / 2) / VOUT_LSB_MV;
+    if code > 1023 { 1023 } else { code }
+}
+
+/// The output voltage (mV) the DAC actually produces for a request of `mv`, using the
+/// rounding of `vout_mv_to_code_rounded`.
+pub const fn nearest_achievable_mv(mv: u16) -> u16 {
+    code_to_vout_mv(vout_mv_to_code_rounded(mv))
+}
+
+/// VOUT range for an internal feedback ratio as `(min_mv, lsb_mv)` (datasheet Table 7-8).
+///
+/// REF=0000h maps to `min_mv`; every DAC step adds `lsb_mv`. The plain `VOUT_*` constants
+/// correspond to the reset ratio (0.0564).
+pub fn vout_range_for_ratio(ratio: InternalFeedbackRatio) -> (u16, u16) {
+    match ratio {
+        InternalFeedbackRatio::R0_2256 => (200, 5),
+        InternalFeedbackRatio::R0_1128 => (400, 10),
+        InternalFeedbackRatio::R0_0752 => (600, 15),
+        InternalFeedbackRatio::R0_0564 => (VOUT_MIN_MV, VOUT_LSB_MV),
+    }
+}
+
+/// Convert VOUT millivolts to DAC code for the given internal feedback ratio. Clamps to range.
+pub fn vout_mv_to_code_for_ratio(mv: u16, ratio: InternalFeedbackRatio) -> u16 {
+    let (min_mv, lsb_mv) = vout_range_for_ratio(ratio);
+    let max_mv = min_mv + 1023 * lsb_mv;
+    let mv = mv.clamp(min_mv, max_mv);
+    ((mv - min_mv) / lsb_mv).min(1023)
+}
+
+/// Convert DAC code to VOUT millivolts for the given internal feedback ratio.
+pub fn code_to_vout_mv_for_ratio(code: u16, ratio: InternalFeedbackRatio) -> u16 {
+    let (min_mv, lsb_mv) = vout_range_for_ratio(ratio);
+    min_mv + code.min(1023) * lsb_mv
+}
+
+/// Convert a REF DAC code to the reference voltage (mV), rounded to the nearest millivolt.
+///
+/// VREF = 45 mV + code × 1.129 mV (0000h = 45 mV, 03C0h = 1.129 V, 03FFh ≈ 1.2 V).
+pub fn code_to_vref_mv(code: u16) -> u16 {
+    let uv = VREF_MIN_UV + (code.min(REF_CODE_MAX) as u32) * VREF_LSB_UV;
+    ((uv + 500) / 1000) as u16
+}
+
+/// Convert a reference voltage (mV) to the nearest REF DAC code. Clamps to 45 mV..1.2 V.
+pub fn vref_mv_to_code(mv: u16) -> u16 {
+    let uv = (mv as u32 * 1000).saturating_sub(VREF_MIN_UV);
+    ((uv + VREF_LSB_UV / 2) / VREF_LSB_UV).min(REF_CODE_MAX as u32) as u16
+}
+
+/// Output voltage (mV) produced by `ref_code` through an external divider.
+///
+/// VOUT = VREF × (1 + Rtop / Rbottom), truncated to whole millivolts and saturating at
+/// `u16::MAX` (including for a zero `rbottom_ohm`).
+pub fn external_vout_mv(ref_code: u16, rtop_ohm: u32, rbottom_ohm: u32) -> u16 {
+    let vref_uv = (VREF_MIN_UV + (ref_code & REF_CODE_MAX) as u32 * VREF_LSB_UV) as u64;
+    let total_ohm = rtop_ohm as u64 + rbottom_ohm as u64;
+    let vout_uv = (vref_uv * total_ohm)
+        .checked_div(rbottom_ohm as u64)
+        .unwrap_or(u64::MAX);
+    (vout_uv / 1000).min(u16::MAX as u64) as u16
+}
+
+/// REF DAC code that brings an external divider closest to `target_mv` without exceeding it.
+///
+/// Clamps to 0..=`REF_CODE_MAX` when the target lies outside the 45 mV..1.2 V VREF span.
+pub fn ref_code_for_external_vout(target_mv: u16, rtop_ohm: u32, rbottom_ohm: u32) -> u16 {
+    let total_ohm = rtop_ohm as u64 + rbottom_ohm as u64;
+    let vref_uv = (target_mv as u64 * 1000 * rbottom_ohm as u64)
+        .checked_div(total_ohm)
+        .unwrap_or(0);
+    let code = vref_uv.saturating_sub(VREF_MIN_UV as u64) / VREF_LSB_UV as u64;
+    code.min(REF_CODE_MAX as u64) as u16
+}
+
+/// Time (µs, rounded up) for VOUT to move `delta_mv` at the given VOUT_SR slew rate.
+pub fn vout_slew_time_us(delta_mv: u16, slew: VoutSlewRate) -> u32 {
+    // Slew rates in µV/µs.
+    let rate = match slew {
+        VoutSlewRate::Sr1p25MvPerUs => 1_250,
+        VoutSlewRate::Sr2p5MvPerUs => 2_500,
+        VoutSlewRate::Sr5MvPerUs => 5_000,
+        VoutSlewRate::Sr10MvPerUs => 10_000,
+    };
+    (delta_mv as u32 * 1000).div_ceil(rate)
+}
+
 /// Convert output current limit (mA) to DAC code (50 mA LSB). Clamps to datasheet max.
-pub fn ilim_ma_to_code(ma: u16) -> u8 {
-    let ma = ma.min(ILIM_MAX_MA);
-    (ma / ILIM_LSB_MA).min(ILIM_MAX_MA / ILIM_LSB_MA) as u8
+///
+/// Approximation for a 10 mΩ sense resistor; see `ilim_ma_to_code_for_sense`.
+pub const fn ilim_ma_to_code(ma: u16) -> u8 {
+    let ma = if ma > ILIM_MAX_MA { ILIM_MAX_MA } else { ma };
+    (ma / ILIM_LSB_MA) as u8
 }
 
-/// Convert current limit DAC code to milliamps.
-pub fn code_to_ilim_ma(code: u8) -> u16 {
-    let code = (code as u16).min(ILIM_MAX_MA / ILIM_LSB_MA);
+/// Convert current limit DAC code to milliamps (10 mΩ sense resistor assumed).
+pub const fn code_to_ilim_ma(code: u8) -> u16 {
+    let max = ILIM_MAX_MA / ILIM_LSB_MA;
+    let code = if code as u16 > max { max } else { code as u16 };
     code * ILIM_LSB_MA
 }
 
+/// Like `ilim_ma_to_code`, but rounds to the nearest 50 mA step (3049 mA -> 3050 mA; exact
+/// halves round up). Clamps to `ILIM_MAX_MA`.
+///
+/// Rounding up can land above the requested limit and eat into a safety margin, which is why
+/// `set_ilim_ma` keeps truncating.
+pub const fn ilim_ma_to_code_rounded(ma: u16) -> u8 {
+    let ma = if ma > ILIM_MAX_MA { ILIM_MAX_MA } else { ma };
+    let code = (ma + ILIM_LSB_MA / 2) / ILIM_LSB_MA;
+    let max = ILIM_MAX_MA / ILIM_LSB_MA;
+    (if code > max { max } else { code }) as u8
+}
+
+/// The current limit (mA) actually programmed for a request of `ma`, using the rounding of
+/// `ilim_ma_to_code_rounded` (10 mΩ sense resistor assumed).
+pub const fn achievable_ilim_ma(ma: u16) -> u16 {
+    code_to_ilim_ma(ilim_ma_to_code_rounded(ma))
+}
+
+/// Convert an output current limit (mA) to the IOUT_LIMIT code for a sense resistor (mΩ).
+///
+/// code = I × Rsense / 0.5 mV, truncated and clamped to `ILIM_MAX_CODE`.
+pub fn ilim_ma_to_code_for_sense(ma: u16, rsense_mohm: u16) -> u8 {
+    // mA × mΩ = µV
+    let uv = ma as u32 * rsense_mohm as u32;
+    (uv / ILIM_LSB_UV).min(ILIM_MAX_CODE as u32) as u8
+}
+
+/// Convert an IOUT_LIMIT code to milliamps for a sense resistor (mΩ).
+///
+/// Saturates to `u16::MAX` for a zero or tiny sense resistor.
+pub fn code_to_ilim_ma_for_sense(code: u8, rsense_mohm: u16) -> u16 {
+    let uv = (code.min(ILIM_MAX_CODE) as u32) * ILIM_LSB_UV;
+    uv.checked_div(rsense_mohm as u32)
+        .unwrap_or(u32::MAX)
+        .min(u16::MAX as u32) as u16
+}
+
+/// Effective switching frequency (kHz) in buck-boost mode for an RFSW-set base frequency.
+///
+/// FSWDBL doubles the frequency only while in buck-boost mode; the result is bounded by
+/// `FSW_MAX_KHZ`. TI does not recommend doubling above 1.6 MHz.
+pub fn effective_fsw_khz(base_khz: u16, doubled: bool) -> u16 {
+    let khz = if doubled {
+        base_khz.saturating_mul(2)
+    } else {
+        base_khz
+    };
+    khz.min(FSW_MAX_KHZ)
+}
+
 // TODO: confirm MODE bit0 semantics when implementing driver.
 
 /// Decode STATUS operating status bits into mode index (0b00 boost, 0b01 buck, 0b10 buck-boost, 0b11 reserved).
@@ -142,3 +502,8 @@ pub fn decode_status_mode(bits: &StatusBits) -> u8 {
     let raw = bits.bits() & (StatusBits::STATUS0 | StatusBits::STATUS1).bits();
     raw & 0b11
 }
+
+/// Decode the STATUS operating mode bits into `OperatingStatus`.
+pub fn decode_status(bits: &StatusBits) -> OperatingStatus {
+    OperatingStatus::from(decode_status_mode(bits))
+}
@@ -0,0 +1,93 @@
+//! Driver adapter for a bus shared through an `embassy-sync` mutex.
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::mutex::Mutex;
+use embedded_hal_async::i2c::I2c;
+
+use crate::data_types::{FaultStatus, OperatingStatus};
+use crate::driver::Tps55288;
+use crate::error::Error;
+
+/// TPS55288 on an I2C bus that other drivers share through `Mutex<M, I2C>`.
+///
+/// The bus is locked for each operation and released afterwards, so a multi-transaction
+/// read-modify-write cannot interleave with another device's traffic. The address and cached
+/// settings (feedback ratio, sense resistor, VOUT trim) persist between calls.
+///
+/// `with` is the supported way to reach the driver API: it runs any `Tps55288` method, or a
+/// sequence of them, under one lock. Only `set_vout_mv`, `enable_output`, `disable_output`,
+/// `read_status` and `clear_faults` have direct wrappers, as shorthands for the common cases.
+pub struct SharedTps55288<'a, M: RawMutex, I2C> {
+    bus: &'a Mutex<M, I2C>,
+    state: Tps55288<()>,
+}
+
+impl<'a, M: RawMutex, I2C> SharedTps55288<'a, M, I2C> {
+    /// Adapter for the default address (0x74).
+    pub fn new(bus: &'a Mutex<M, I2C>) -> Self {
+        Self {
+            bus,
+            state: Tps55288::new(()),
+        }
+    }
+
+    /// Adapter for an explicit 7-bit address.
+    pub fn with_address(bus: &'a Mutex<M, I2C>, address: u8) -> Self {
+        Self {
+            bus,
+            state: Tps55288::with_address((), address),
+        }
+    }
+
+    /// Use a different current sense resistor (mΩ); see `Tps55288::with_sense_resistor`.
+    #[must_use]
+    pub fn with_sense_resistor(mut self, rsense_mohm: u16) -> Self {
+        self.state = self.state.with_sense_resistor(rsense_mohm);
+        self
+    }
+
+    /// Return the 7-bit I2C address configured for this instance.
+    pub fn address(&self) -> u8 {
+        self.state.address()
+    }
+}
+
+impl<M: RawMutex, I2C: I2c> SharedTps55288<'_, M, I2C> {
+    /// Lock the bus and run `f` on a driver borrowing it.
+    ///
+    /// Settings changed inside `f` (e.g. by `set_feedback`) are kept for later calls.
+    pub async fn with<R>(&mut self, f: impl AsyncFnOnce(&mut Tps55288<&mut I2C>) -> R) -> R {
+        let mut bus = self.bus.lock().await;
+        let mut dev = self.state.copy_onto(&mut *bus);
+        let out = f(&mut dev).await;
+        self.state = dev.copy_onto(());
+        out
+    }
+
+    /// See `Tps55288::set_vout_mv`.
+    pub async fn set_vout_mv(&mut self, mv: u16) -> Result<(), Error<I2C::Error>> {
+        self.with(async |dev| dev.set_vout_mv(mv).await).await
+    }
+
+    /// See `Tps55288::enable_output`.
+    pub async fn enable_output(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.with(async |dev| dev.enable_output().await).await
+    }
+
+    /// See `Tps55288::disable_output`.
+    pub async fn disable_output(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.with(async |dev| dev.disable_output().await).await
+    }
+
+    /// See `Tps55288::read_status`.
+    pub async fn read_status(
+        &mut self,
+    ) -> Result<(OperatingStatus, FaultStatus), Error<I2C::Error>> {
+        self.with(async |dev| dev.read_status().await).await
+    }
+
+    /// See `Tps55288::clear_faults`.
+    pub async fn clear_faults(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.with(async |dev| dev.clear_faults().await).await
+    }
+}
@@ -0,0 +1,149 @@
+//! In-memory register file standing in for a TPS55288 on the bus (`sim` feature).
+//!
+//! `MemI2c` implements the I2C traits over the eight device registers so higher-level logic
+//! can drive a real `Tps55288<MemI2c>` on the host without scripting every transaction.
+
+use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation};
+
+use crate::registers::{
+    DEFAULT_I2C_ADDRESS, RESET_CDC, RESET_IOUT_LIMIT, RESET_MODE, RESET_REF0, RESET_REF1,
+    RESET_STATUS, RESET_VOUT_FS, RESET_VOUT_SR, STATUS_FAULT_MASK, addr,
+};
+
+/// Simulated device: REF0..STATUS with datasheet reset values and an auto-incrementing
+/// register pointer.
+///
+/// Reading STATUS clears the fault flags, as on the chip; writing 1s to them clears them too.
+/// Accessing another address NACKs, and register indices past STATUS NACK on data.
+#[derive(Clone, Debug)]
+pub struct MemI2c {
+    address: u8,
+    regs: [u8; 8],
+    pointer: u8,
+}
+
+impl MemI2c {
+    /// A device at the default address (0x74) in its power-on state.
+    pub fn new() -> Self {
+        Self::with_address(DEFAULT_I2C_ADDRESS)
+    }
+
+    /// A device answering at `address` in its power-on state.
+    pub fn with_address(address: u8) -> Self {
+        Self {
+            address,
+            regs: [
+                RESET_REF0,
+                RESET_REF1,
+                RESET_IOUT_LIMIT,
+                RESET_VOUT_SR,
+                RESET_VOUT_FS,
+                RESET_CDC,
+                RESET_MODE,
+                RESET_STATUS,
+            ],
+            pointer: 0,
+        }
+    }
+
+    /// Current register contents, REF0..STATUS.
+    pub fn registers(&self) -> &[u8; 8] {
+        &self.regs
+    }
+
+    /// Poke a register directly, e.g. to latch a fault in STATUS before a test.
+    pub fn set_register(&mut self, reg: u8, val: u8) {
+        if let Some(slot) = self.regs.get_mut(reg as usize) {
+            *slot = val;
+        }
+    }
+
+    fn write_byte(&mut self, val: u8) -> Result<(), ErrorKind> {
+        let reg = self.pointer;
+        let slot = self
+            .regs
+            .get_mut(reg as usize)
+            .ok_or(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))?;
+        if reg == addr::STATUS {
+            *slot &= !(val & STATUS_FAULT_MASK.bits());
+        } else {
+            *slot = val;
+        }
+        self.pointer = reg.wrapping_add(1);
+        Ok(())
+    }
+
+    fn read_byte(&mut self) -> Result<u8, ErrorKind> {
+        let reg = self.pointer;
+        let slot = self
+            .regs
+            .get_mut(reg as usize)
+            .ok_or(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))?;
+        let val = *slot;
+        if reg == addr::STATUS {
+            *slot &= !STATUS_FAULT_MASK.bits();
+        }
+        self.pointer = reg.wrapping_add(1);
+        Ok(val)
+    }
+
+    fn run(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), ErrorKind> {
+        if address != self.address {
+            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+        }
+        // Adjacent writes form one frame: only the first byte of the frame is the pointer.
+        let mut in_write_frame = false;
+        for op in operations {
+            match op {
+                Operation::Write(bytes) => {
+                    let mut data = bytes.iter();
+                    if !in_write_frame && let Some(&reg) = data.next() {
+                        self.pointer = reg;
+                        in_write_frame = true;
+                    }
+                    for &b in data {
+                        self.write_byte(b)?;
+                    }
+                }
+                Operation::Read(buf) => {
+                    in_write_frame = false;
+                    for b in buf.iter_mut() {
+                        *b = self.read_byte()?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for MemI2c {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorType for MemI2c {
+    type Error = ErrorKind;
+}
+
+impl embedded_hal::i2c::I2c for MemI2c {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.run(address, operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for MemI2c {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.run(address, operations)
+    }
+}
@@ -0,0 +1,116 @@
+//! VOUT ramps that slow down around the buck/boost crossover.
+
+use crate::driver::Tps55288;
+use crate::error::Error;
+
+#[cfg(not(feature = "async"))]
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+#[cfg(feature = "async")]
+use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+
+/// Ramp plan that dwells near the buck/boost crossover to reduce mode chatter.
+///
+/// The crossover sits roughly where VOUT equals VIN, so `crossover_mv` is normally the input
+/// voltage. Setpoints within `window_mv` of it are held for `dwell_us` instead of the regular
+/// `step_delay_us`, giving the converter time to settle in one mode before the next step. A
+/// step that would jump over the whole window is shortened to stop at its near edge, so the
+/// dwell happens even when `step_mv` exceeds the window width.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StableStepper {
+    crossover_mv: u16,
+    window_mv: u16,
+    step_mv: u16,
+    step_delay_us: u32,
+    dwell_us: u32,
+}
+
+impl StableStepper {
+    /// Dwell window of ±`window_mv` around `crossover_mv`; 100 mV steps every 1 ms and a
+    /// 10 ms dwell until changed.
+    pub fn new(crossover_mv: u16, window_mv: u16) -> Self {
+        Self {
+            crossover_mv,
+            window_mv,
+            step_mv: 100,
+            step_delay_us: 1_000,
+            dwell_us: 10_000,
+        }
+    }
+
+    /// Step size (mV) and delay after each step outside the window.
+    #[must_use]
+    pub fn step(mut self, step_mv: u16, step_delay_us: u32) -> Self {
+        self.step_mv = step_mv;
+        self.step_delay_us = step_delay_us;
+        self
+    }
+
+    /// Delay after each step inside the window.
+    #[must_use]
+    pub fn dwell_us(mut self, dwell_us: u32) -> Self {
+        self.dwell_us = dwell_us;
+        self
+    }
+
+    /// True when `mv` lies within the dwell window (bounds inclusive).
+    pub fn in_window(&self, mv: u16) -> bool {
+        mv.abs_diff(self.crossover_mv) <= self.window_mv
+    }
+
+    /// Delay (µs) to apply after setting `mv`.
+    pub fn delay_after_us(&self, mv: u16) -> u32 {
+        if self.in_window(mv) {
+            self.dwell_us
+        } else {
+            self.step_delay_us
+        }
+    }
+
+    /// Setpoint (mV) after `mv` on the way to `to_mv`, stopping at the window edge instead of
+    /// stepping across the window without landing in it.
+    pub fn next_mv(&self, mv: u16, to_mv: u16) -> u16 {
+        let lo = self.crossover_mv.saturating_sub(self.window_mv);
+        let hi = self.crossover_mv.saturating_add(self.window_mv);
+        if to_mv > mv {
+            let next = mv.saturating_add(self.step_mv).min(to_mv);
+            if mv < lo && next > hi { lo } else { next }
+        } else {
+            let next = mv.saturating_sub(self.step_mv).max(to_mv);
+            if mv > hi && next < lo { hi } else { next }
+        }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), keep_self),
+    async(feature = "async", keep_self)
+)]
+impl StableStepper {
+    /// Walk `dev` from `from_mv` to `to_mv` with `set_vout_mv`, like `Tps55288::ramp_vout_mv`
+    /// but with the longer dwell inside the crossover window.
+    ///
+    /// Steps follow `next_mv`. The last step is shortened to land on `to_mv` and is not followed
+    /// by a delay. Returns
+    /// `Error::InvalidConfig` for a zero step.
+    pub async fn run<I2C: I2c, D: DelayNs>(
+        &self,
+        dev: &mut Tps55288<I2C>,
+        from_mv: u16,
+        to_mv: u16,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        if self.step_mv == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        let mut mv = from_mv;
+        loop {
+            dev.set_vout_mv(mv).await?;
+            if mv == to_mv {
+                return Ok(());
+            }
+            delay.delay_us(self.delay_after_us(mv)).await;
+            mv = self.next_mv(mv, to_mv);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Edge detection on top of STATUS polling.
+
+use crate::data_types::{FaultStatus, OperatingStatus};
+use crate::driver::Tps55288;
+use crate::error::Error;
+
+#[cfg(not(feature = "async"))]
+use embedded_hal::i2c::I2c;
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c;
+
+/// Wraps the driver and reports decoded STATUS only when it differs from the last poll.
+pub struct StatusWatcher<I2C> {
+    dev: Tps55288<I2C>,
+    last: Option<(OperatingStatus, FaultStatus)>,
+}
+
+impl<I2C> StatusWatcher<I2C> {
+    /// Start watching; the first successful poll always reports.
+    pub fn new(dev: Tps55288<I2C>) -> Self {
+        Self { dev, last: None }
+    }
+
+    /// Last observed status, if any poll has succeeded.
+    pub fn last(&self) -> Option<(OperatingStatus, FaultStatus)> {
+        self.last
+    }
+
+    /// Access the wrapped driver, e.g. to reconfigure it between polls.
+    pub fn driver_mut(&mut self) -> &mut Tps55288<I2C> {
+        &mut self.dev
+    }
+
+    /// Release the wrapped driver.
+    pub fn into_inner(self) -> Tps55288<I2C> {
+        self.dev
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(cfg(not(feature = "async")), keep_self),
+    async(feature = "async", keep_self)
+)]
+impl<I2C> StatusWatcher<I2C>
+where
+    I2C: I2c,
+{
+    /// Read STATUS and return `Some` only when the decoded value changed since the last poll.
+    ///
+    /// STATUS is read-to-clear, so a fault that latched and was cleared by a previous read
+    /// reports once and then reverts.
+    pub async fn poll_change(
+        &mut self,
+    ) -> Result<Option<(OperatingStatus, FaultStatus)>, Error<I2C::Error>> {
+        let current = self.dev.read_status().await?;
+        if self.last == Some(current) {
+            return Ok(None);
+        }
+        self.last = Some(current);
+        Ok(Some(current))
+    }
+}
@@ -0,0 +1,128 @@
+//! Compiles under both the blocking (default) and `async` builds, so a method that exists in
+//! only one of them breaks the build.
+
+use embedded_hal_mock::eh1::i2c::Mock as I2cMock;
+use tps55288::Tps55288;
+
+type Dev = Tps55288<I2cMock>;
+
+struct NoDelay;
+
+impl embedded_hal::delay::DelayNs for NoDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for NoDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
+
+#[test]
+fn driver_methods_exist_in_this_build() {
+    let _ = Dev::init;
+    let _ = Dev::init_with_config;
+    let _ = Dev::enable_output;
+    let _ = Dev::disable_output;
+    let _ = Dev::write_reg;
+    let _ = Dev::read_reg;
+    let _ = Dev::update_reg;
+    let _ = Dev::set_light_load_mode;
+    let _ = Dev::set_mode_control;
+    let _ = Dev::write_regs;
+    let _ = Dev::read_regs;
+    let _ = Dev::read_regs_unchecked;
+    let _ = Dev::set_vout_mv;
+    let _ = Dev::set_vout_mv_with_ratio;
+    let _ = Dev::set_vout_mv_checked;
+    let _ = Dev::get_vout_mv;
+    let _ = Dev::set_ilim_ma;
+    let _ = Dev::set_ilim_ma_checked;
+    let _ = Dev::get_ilim_ma;
+    let _ = Dev::set_vout_sr;
+    let _ = Dev::set_feedback;
+    let _ = Dev::set_cable_comp;
+    let _ = Dev::read_status_raw;
+    let _ = Dev::read_status;
+    let _ = Dev::clear_faults;
+    let _ = Dev::clear_faults_selective;
+    let _ = Dev::set_vcc_source;
+    let _ = Dev::get_vcc_source;
+    let _ = Dev::set_hiccup_enabled;
+    let _ = Dev::is_hiccup_enabled;
+    let _ = Dev::set_output_discharge;
+    let _ = Dev::get_output_discharge;
+    let _ = Dev::set_frequency_doubling;
+    let _ = Dev::get_frequency_doubling;
+    let _ = Dev::read_mode_config;
+    let _ = Dev::write_mode_config;
+    let _ = Dev::set_ilim_from_sense;
+    let _ = Dev::get_ilim_from_sense;
+    let _ = Dev::set_vout_code;
+    let _ = Dev::get_vout_code;
+    let _ = Dev::set_external_vout_mv;
+    let _ = Dev::ramp_vout_mv::<NoDelay>;
+    let _ = Dev::read_fault_status;
+    let _ = Dev::wait_for_fault_clear::<NoDelay>;
+    let _ = Dev::probe;
+    let _ = Dev::detect;
+    let _ = Dev::detect_default;
+    let _ = Dev::get_feedback;
+    let _ = Dev::get_cable_comp;
+    let _ = Dev::get_vout_sr;
+    let _ = Dev::read_all;
+    let _ = Dev::reset_to_defaults;
+    let _ = Dev::write_config_burst;
+    let _ = Dev::change_vout_and_wait::<NoDelay>;
+    let _ = Dev::vout_settle_time_us;
+    let _ = Dev::estimated_iin_ma;
+    let _ = Dev::select_address_for_preset;
+    let _ = Dev::set_software_address;
+    let _ = Dev::apply_preset_overrides;
+    let _ = Dev::is_output_enabled;
+    let _ = Dev::start::<NoDelay>;
+    let _ = Dev::verify_config;
+    let _ = Dev::set_vout::<tps55288::data_types::Millivolts>;
+    let _ = Dev::set_ilim::<tps55288::data_types::Milliamps>;
+    let _ = Dev::set_fault_masks;
+    let _ = Dev::get_fault_masks;
+    let _ = Dev::set_cable_comp_level;
+    let _ = Dev::read_register;
+    let _ = Dev::write_register;
+    let _ = Dev::on_interrupt;
+    let _ = Dev::set_slew_rate;
+    let _ = Dev::set_ocp_delay;
+    let _ = Dev::set_vout_and_confirm::<NoDelay>;
+    let _ = Dev::check_identity;
+    let _ = Dev::check_faults;
+    let _ = Dev::write_regs_streaming;
+    let _ = Dev::atomic_set_and_read_status;
+    let _ = Dev::update_ilim_ma;
+    let _ = Dev::enable_current_limit;
+    let _ = Dev::disable_current_limit_keep_setting;
+    let _ = Dev::read_report;
+    let _ = Dev::read_status_strict;
+    let _ = Dev::shutdown::<NoDelay>;
+    let _ = Dev::get_current_limit;
+    let _ = Dev::read_mode;
+    let _ = Dev::write_mode;
+    let _ = Dev::poll_faults::<NoDelay>;
+    let _ = Dev::set_vout_mv_verified;
+    let _ = Dev::apply_diff;
+    let _ = Dev::dry_run_config;
+    let _ = Dev::config_diff;
+    let _ = Dev::enable_output_retry::<NoDelay>;
+    let _ = Dev::get_vout_mv_with_ratio;
+    let _ = Dev::set_fault_mask_config;
+    let _ = Dev::get_fault_mask_config;
+    let _ = Dev::set_vout_code_unchecked;
+    let _ = Dev::read_status_full;
+    let _ = Dev::set_external_feedback;
+    let _ = Dev::set_internal_feedback;
+    let _ = Dev::is_at_reset;
+    let _ = Dev::switch_feedback::<NoDelay>;
+    let _ = Dev::enable_output_verified;
+    let _ = Tps55288::<I2cMock, tps55288::driver::Unconfigured>::configure;
+    let _ = tps55288::StatusWatcher::<I2cMock>::poll_change;
+    let _ = tps55288::Tps55288Builder::build_and_init::<I2cMock>;
+    let _ = tps55288::StableStepper::run::<I2cMock, NoDelay>;
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "async")]
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use tps55288::Tps55288;
+use tps55288::data_types::{FeedbackSource, InternalFeedbackRatio};
+
+/// The mock completes every transaction immediately, so one poll is enough.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+#[test]
+fn async_writes_match_blocking_sequence() {
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x04, 0x01]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xA0, 0x02]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    block_on(async {
+        driver
+            .set_feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_1128)
+            .await
+            .unwrap();
+        driver.set_vout_mv(7_120).await.unwrap();
+        driver.enable_output().await.unwrap();
+    });
+    driver.free().done();
+}
+
+#[test]
+fn async_status_watcher_reports_only_changes() {
+    use tps55288::StatusWatcher;
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x01]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x01]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x02]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut watcher = StatusWatcher::new(Tps55288::new(mock));
+    block_on(async {
+        assert!(watcher.poll_change().await.unwrap().is_some());
+        assert_eq!(watcher.poll_change().await.unwrap(), None);
+        assert!(watcher.poll_change().await.unwrap().is_some());
+    });
+    watcher.into_inner().free().done();
+}
+
+#[test]
+fn async_poll_faults_returns_first_latched_fault() {
+    struct CountingDelay(u32);
+
+    impl embedded_hal_async::delay::DelayNs for CountingDelay {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.0 += ns;
+        }
+    }
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x02]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x02]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x42]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay(0);
+    let faults = block_on(driver.poll_faults(1_000, &mut delay)).unwrap();
+    assert!(faults.over_current);
+    assert!(!faults.short_circuit && !faults.over_voltage);
+    assert_eq!(delay.0, 2_000_000);
+    driver.free().done();
+}
+
+#[test]
+fn async_read_status_timeout_fires_on_hung_bus() {
+    use embedded_hal::i2c::ErrorKind;
+    use tps55288::Error;
+    use tps55288::data_types::OperatingStatus;
+
+    /// Bus whose transactions never complete.
+    struct HungI2c;
+
+    impl embedded_hal_async::i2c::ErrorType for HungI2c {
+        type Error = ErrorKind;
+    }
+
+    impl embedded_hal_async::i2c::I2c for HungI2c {
+        async fn transaction(
+            &mut self,
+            _address: u8,
+            _operations: &mut [embedded_hal_async::i2c::Operation<'_>],
+        ) -> Result<(), Self::Error> {
+            core::future::pending().await
+        }
+    }
+
+    /// Completes on its first poll and records the requested timeout.
+    struct InstantDelay(u32);
+
+    impl embedded_hal_async::delay::DelayNs for InstantDelay {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.0 += ns;
+        }
+    }
+
+    let mut driver = Tps55288::new(HungI2c);
+    let mut delay = InstantDelay(0);
+    let res = block_on(driver.read_status_timeout(5_000, &mut delay));
+    assert!(matches!(res, Err(Error::Timeout)));
+    assert_eq!(delay.0, 5_000_000);
+
+    // A responsive bus wins the race even when the timer is already due.
+    let expectations = [I2cTrans::write_read(0x74, vec![0x07], vec![0x01])];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let (operating, faults) =
+        block_on(driver.read_status_timeout(5_000, &mut InstantDelay(0))).unwrap();
+    assert_eq!(operating, OperatingStatus::Buck);
+    assert!(faults.is_clear());
+    driver.free().done();
+}
@@ -35,3 +35,207 @@ fn ilim_clamps_high() {
     let code = ilim_ma_to_code(10_000);
     assert_eq!(code_to_ilim_ma(code), ILIM_MAX_MA);
 }
+
+#[test]
+fn vout_for_ratio_matches_datasheet_table() {
+    use tps55288::data_types::InternalFeedbackRatio;
+    use tps55288::registers::{code_to_vout_mv_for_ratio, vout_mv_to_code_for_ratio};
+
+    // Datasheet Table 7-8: REF=03C0h gives 5/10/15/20 V for the four ratios.
+    let table = [
+        (InternalFeedbackRatio::R0_2256, 5_000u16),
+        (InternalFeedbackRatio::R0_1128, 10_000),
+        (InternalFeedbackRatio::R0_0752, 15_000),
+        (InternalFeedbackRatio::R0_0564, 20_000),
+    ];
+    for (ratio, mv) in table {
+        assert_eq!(code_to_vout_mv_for_ratio(0x03C0, ratio), mv);
+        assert_eq!(vout_mv_to_code_for_ratio(mv, ratio), 0x03C0);
+    }
+    // The 0.0564 mapping must agree with the legacy helpers.
+    assert_eq!(
+        vout_mv_to_code_for_ratio(12_000, InternalFeedbackRatio::R0_0564),
+        vout_mv_to_code(12_000)
+    );
+}
+
+#[test]
+fn effective_fsw_doubles_and_caps() {
+    use tps55288::registers::{FSW_MAX_KHZ, effective_fsw_khz};
+
+    assert_eq!(effective_fsw_khz(400, false), 400);
+    assert_eq!(effective_fsw_khz(400, true), 800);
+    assert_eq!(effective_fsw_khz(1_600, true), FSW_MAX_KHZ);
+    assert_eq!(effective_fsw_khz(u16::MAX, true), FSW_MAX_KHZ);
+}
+
+#[test]
+fn ilim_for_sense_resistor() {
+    use tps55288::registers::{code_to_ilim_ma_for_sense, ilim_ma_to_code_for_sense};
+
+    // 10 mΩ matches the 50 mA-LSB helpers.
+    assert_eq!(ilim_ma_to_code_for_sense(3_000, 10), ilim_ma_to_code(3_000));
+    assert_eq!(code_to_ilim_ma_for_sense(127, 10), ILIM_MAX_MA);
+    // 5 mΩ: 100 mA per LSB, 12.7 A full scale.
+    assert_eq!(ilim_ma_to_code_for_sense(3_000, 5), 30);
+    assert_eq!(code_to_ilim_ma_for_sense(127, 5), 12_700);
+    // 20 mΩ: 25 mA per LSB, 3.175 A full scale.
+    assert_eq!(ilim_ma_to_code_for_sense(3_000, 20), 120);
+    assert_eq!(ilim_ma_to_code_for_sense(5_000, 20), 127);
+    assert_eq!(code_to_ilim_ma_for_sense(120, 20), 3_000);
+    // Truncates to the step below.
+    assert_eq!(ilim_ma_to_code_for_sense(3_049, 10), 60);
+}
+
+#[test]
+fn external_divider_matches_sw2303_example() {
+    use tps55288::registers::{REF_CODE_MAX, external_vout_mv, ref_code_for_external_vout};
+
+    // Rtop = 100 kΩ, Rbottom = 31.6 kΩ, REF = 03FFh -> ~5 V.
+    let mv = external_vout_mv(0x03FF, 100_000, 31_600);
+    assert!((4_990..=5_000).contains(&mv), "got {mv} mV");
+    assert_eq!(
+        ref_code_for_external_vout(5_000, 100_000, 31_600),
+        REF_CODE_MAX
+    );
+    // Never overshoots the target.
+    let code = ref_code_for_external_vout(3_300, 100_000, 31_600);
+    assert!(external_vout_mv(code, 100_000, 31_600) <= 3_300);
+    assert!(external_vout_mv(code + 1, 100_000, 31_600) > 3_300);
+    // Below VREF_MIN clamps to code 0.
+    assert_eq!(ref_code_for_external_vout(100, 100_000, 31_600), 0);
+}
+
+#[test]
+fn vref_conversions_match_datasheet() {
+    use tps55288::registers::{code_to_vref_mv, vref_mv_to_code};
+
+    assert_eq!(code_to_vref_mv(0x0000), 45);
+    assert_eq!(code_to_vref_mv(0x0200), 623);
+    assert_eq!(code_to_vref_mv(0x03FF), 1_200);
+    // Datasheet REF table entries.
+    assert_eq!(code_to_vref_mv(0x00D2), 282);
+    assert_eq!(code_to_vref_mv(0x019A), 508);
+    assert_eq!(code_to_vref_mv(0x03C0), 1_129);
+    assert_eq!(vref_mv_to_code(282), 0x00D2);
+    assert_eq!(vref_mv_to_code(508), 0x019A);
+    assert_eq!(vref_mv_to_code(1_129), 0x03C0);
+    // Clamping and masking.
+    assert_eq!(vref_mv_to_code(0), 0);
+    assert_eq!(vref_mv_to_code(2_000), 0x03FF);
+    assert_eq!(code_to_vref_mv(0xFFFF), 1_200);
+}
+
+#[test]
+fn vout_slew_time_per_rate() {
+    use tps55288::data_types::VoutSlewRate;
+    use tps55288::registers::vout_slew_time_us;
+
+    // 5 V -> 20 V step (15 V).
+    assert_eq!(
+        vout_slew_time_us(15_000, VoutSlewRate::Sr1p25MvPerUs),
+        12_000
+    );
+    assert_eq!(vout_slew_time_us(15_000, VoutSlewRate::Sr2p5MvPerUs), 6_000);
+    assert_eq!(vout_slew_time_us(15_000, VoutSlewRate::Sr5MvPerUs), 3_000);
+    assert_eq!(vout_slew_time_us(15_000, VoutSlewRate::Sr10MvPerUs), 1_500);
+    // Partial microseconds round up; no step means no wait.
+    assert_eq!(vout_slew_time_us(1, VoutSlewRate::Sr10MvPerUs), 1);
+    assert_eq!(vout_slew_time_us(0, VoutSlewRate::Sr1p25MvPerUs), 0);
+}
+
+#[test]
+fn register_enum_maps_to_addresses() {
+    use tps55288::registers::{Register, addr};
+
+    let table = [
+        (Register::Ref0, addr::REF0),
+        (Register::Ref1, addr::REF1),
+        (Register::IoutLimit, addr::IOUT_LIMIT),
+        (Register::VoutSr, addr::VOUT_SR),
+        (Register::VoutFs, addr::VOUT_FS),
+        (Register::Cdc, addr::CDC),
+        (Register::Mode, addr::MODE),
+        (Register::Status, addr::STATUS),
+    ];
+    for (i, (reg, expected)) in table.into_iter().enumerate() {
+        assert_eq!(reg.addr(), expected);
+        assert_eq!(reg.addr(), i as u8);
+    }
+}
+
+#[test]
+fn conversions_are_const_evaluable() {
+    const CODE_5V: u16 = vout_mv_to_code(5_000);
+    const MV_BACK: u16 = code_to_vout_mv(CODE_5V);
+    const ILIM_CODE: u8 = ilim_ma_to_code(3_000);
+    const ILIM_BACK: u16 = code_to_ilim_ma(ILIM_CODE);
+
+    assert_eq!(CODE_5V, vout_mv_to_code(5_000));
+    assert_eq!(MV_BACK, code_to_vout_mv(vout_mv_to_code(5_000)));
+    assert_eq!(ILIM_CODE, ilim_ma_to_code(3_000));
+    assert_eq!(ILIM_BACK, 3_000);
+}
+
+#[test]
+fn vout_sr_config_encodes_every_combination() {
+    use tps55288::data_types::{OcpDelay, VoutSlewRate};
+    use tps55288::registers::{RESET_VOUT_SR, VoutSrConfig};
+
+    let slews = [
+        (VoutSlewRate::Sr1p25MvPerUs, 0b00),
+        (VoutSlewRate::Sr2p5MvPerUs, 0b01),
+        (VoutSlewRate::Sr5MvPerUs, 0b10),
+        (VoutSlewRate::Sr10MvPerUs, 0b11),
+    ];
+    let delays = [
+        (OcpDelay::Us128, 0b00),
+        (OcpDelay::Ms3_072, 0b01),
+        (OcpDelay::Ms6_144, 0b10),
+        (OcpDelay::Ms12_288, 0b11),
+    ];
+    for (slew, sr) in slews {
+        for (delay, dly) in delays {
+            let bits = VoutSrConfig::new().slew(slew).ocp_delay(delay).build();
+            assert_eq!(bits.bits(), (dly << 4) | sr);
+        }
+    }
+    assert_eq!(VoutSrConfig::new().build().bits(), RESET_VOUT_SR);
+}
+
+#[test]
+fn rounded_vout_conversion_picks_nearest_step() {
+    use tps55288::registers::{nearest_achievable_mv, vout_mv_to_code_rounded};
+
+    assert_eq!(code_to_vout_mv(vout_mv_to_code(5_010)), 5_000);
+    assert_eq!(code_to_vout_mv(vout_mv_to_code_rounded(5_010)), 5_020);
+    assert_eq!(nearest_achievable_mv(5_009), 5_000);
+    assert_eq!(nearest_achievable_mv(5_010), 5_020);
+    assert_eq!(nearest_achievable_mv(0), VOUT_MIN_MV);
+    assert_eq!(nearest_achievable_mv(u16::MAX), VOUT_MAX_MV);
+}
+
+#[test]
+fn rounded_ilim_conversion_picks_nearest_step() {
+    use tps55288::registers::{achievable_ilim_ma, ilim_ma_to_code_rounded};
+
+    assert_eq!(code_to_ilim_ma(ilim_ma_to_code(3_049)), 3_000);
+    assert_eq!(code_to_ilim_ma(ilim_ma_to_code_rounded(3_049)), 3_050);
+    assert_eq!(achievable_ilim_ma(3_024), 3_000);
+    assert_eq!(achievable_ilim_ma(3_025), 3_050);
+    assert_eq!(achievable_ilim_ma(u16::MAX), ILIM_MAX_MA);
+}
+
+#[test]
+fn ovp_trip_is_fixed_regardless_of_setpoint() {
+    use tps55288::registers::{OVP_HYSTERESIS_MV, OVP_MIN_MV, ovp_may_trip, ovp_trip_mv};
+
+    assert_eq!(ovp_trip_mv(), 23_500);
+    assert!(ovp_trip_mv() - OVP_HYSTERESIS_MV > 20_000);
+    for set_mv in [800, 5_000, 12_000, 20_000, 21_260, OVP_MIN_MV - 1] {
+        assert!(!ovp_may_trip(set_mv), "{set_mv} mV");
+    }
+    for set_mv in [OVP_MIN_MV, 23_500, 25_000] {
+        assert!(ovp_may_trip(set_mv), "{set_mv} mV");
+    }
+}
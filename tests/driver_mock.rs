@@ -1,13 +1,21 @@
 #![cfg(not(feature = "async"))]
 
 use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
-use tps55288::data_types::OperatingStatus;
+use tps55288::data_types::{
+    CableCompLevel, CableCompOption, FaultStatus, FeedbackSource, InternalFeedbackRatio,
+    LightLoadMode, LightLoadOverride, OcpDelay, OperatingStatus, VccSource, VoutSlewRate,
+};
 use tps55288::driver::Tps55288;
+use tps55288::registers::StatusBits;
+use tps55288::{Config, Error};
 
 #[test]
 fn set_vout_writes_ref_registers() {
     // VOUT = 5000 mV -> code (5000-800)/20 = 210 => 0x00D2 (LE: D2 00)
-    let expectations = [I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00])];
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+    ];
     let mock = I2cMock::new(&expectations);
     let mut driver = Tps55288::new(mock);
     driver.set_vout_mv(5_000).unwrap();
@@ -27,3 +35,2170 @@ fn read_status_decodes_mode_and_faults() {
     assert!(faults.over_voltage);
     driver.free().done();
 }
+
+#[test]
+fn enable_output_sets_only_oe() {
+    // MODE = HICCUP | DISCHG | PFM; enabling must only add OE (bit7).
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b0011_0010]),
+        I2cTrans::write(0x74, vec![0x06, 0b1011_0010]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.enable_output().unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn disable_output_clears_only_oe() {
+    // MODE = OE | HICCUP | VCC_EXT | MODE; disabling must only drop OE.
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_1001]),
+        I2cTrans::write(0x74, vec![0x06, 0b0010_1001]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.disable_output().unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn set_vout_with_ratio_all_ratios() {
+    // (ratio, code for 5 V, code for 12 V); high-ratio ranges clamp to full scale.
+    let cases = [
+        (InternalFeedbackRatio::R0_0564, 210u16, 560u16),
+        (InternalFeedbackRatio::R0_0752, 293, 760),
+        (InternalFeedbackRatio::R0_1128, 460, 0x03FF),
+        (InternalFeedbackRatio::R0_2256, 960, 0x03FF),
+    ];
+    for (ratio, code_5v, code_12v) in cases {
+        let [lo5, hi5] = code_5v.to_le_bytes();
+        let [lo12, hi12] = code_12v.to_le_bytes();
+        let expectations = [
+            I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+            I2cTrans::write(0x74, vec![0x00, lo5, hi5]),
+            I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+            I2cTrans::write(0x74, vec![0x00, lo12, hi12]),
+        ];
+        let mock = I2cMock::new(&expectations);
+        let mut driver = Tps55288::new(mock);
+        driver.set_vout_mv_with_ratio(5_000, ratio).unwrap();
+        driver.set_vout_mv_with_ratio(12_000, ratio).unwrap();
+        driver.free().done();
+    }
+}
+
+#[test]
+fn set_vout_uses_cached_feedback_ratio() {
+    // set_feedback(Internal, 0.1128) -> VOUT_FS = 0x01; 5 V -> code 460 (0x01CC).
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x04, 0x01]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xCC, 0x01]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver
+        .set_feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_1128)
+        .unwrap();
+    assert_eq!(driver.feedback_ratio(), InternalFeedbackRatio::R0_1128);
+    driver.set_vout_mv(5_000).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn clear_faults_writes_fault_bits_only() {
+    let expectations = [I2cTrans::write(0x74, vec![0x07, 0b1110_0000])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.clear_faults().unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn clear_faults_selective_masks_non_fault_bits() {
+    // OCP plus the operating-status bits: only OCP may reach the bus.
+    let expectations = [I2cTrans::write(0x74, vec![0x07, 0b0100_0000])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver
+        .clear_faults_selective(StatusBits::OCP | StatusBits::STATUS0 | StatusBits::STATUS1)
+        .unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn set_light_load_mode_all_combinations() {
+    // Start from OE | HICCUP; only MODE (bit0) and PFM (bit1) may change.
+    let cases = [
+        (
+            LightLoadOverride::FromPreset,
+            LightLoadMode::Pfm,
+            0b1010_0000,
+        ),
+        (
+            LightLoadOverride::FromPreset,
+            LightLoadMode::Pwm,
+            0b1010_0010,
+        ),
+        (
+            LightLoadOverride::FromRegister,
+            LightLoadMode::Pfm,
+            0b1010_0001,
+        ),
+        (
+            LightLoadOverride::FromRegister,
+            LightLoadMode::Pwm,
+            0b1010_0011,
+        ),
+    ];
+    for (override_sel, mode, expected) in cases {
+        let expectations = [
+            I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_0000]),
+            I2cTrans::write(0x74, vec![0x06, expected]),
+        ];
+        let mock = I2cMock::new(&expectations);
+        let mut driver = Tps55288::new(mock);
+        driver.set_light_load_mode(override_sel, mode).unwrap();
+        driver.free().done();
+    }
+}
+
+#[test]
+fn set_and_get_vcc_source() {
+    let expectations = [
+        // Internal -> external keeps OE | HICCUP | MODE.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_0001]),
+        I2cTrans::write(0x74, vec![0x06, 0b1010_1001]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_1001]),
+        // External -> internal.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_1001]),
+        I2cTrans::write(0x74, vec![0x06, 0b1010_0001]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_0001]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_vcc_source(VccSource::External5v).unwrap();
+    assert_eq!(driver.get_vcc_source().unwrap(), VccSource::External5v);
+    driver.set_vcc_source(VccSource::Internal).unwrap();
+    assert_eq!(driver.get_vcc_source().unwrap(), VccSource::Internal);
+    driver.free().done();
+}
+
+#[test]
+fn hiccup_toggle_preserves_oe_and_dischg() {
+    let expectations = [
+        // OE | HICCUP | DISCHG -> clear HICCUP.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1011_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b1001_0000]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1001_0000]),
+        // Set it again.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1001_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b1011_0000]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1011_0000]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_hiccup_enabled(false).unwrap();
+    assert!(!driver.is_hiccup_enabled().unwrap());
+    driver.set_hiccup_enabled(true).unwrap();
+    assert!(driver.is_hiccup_enabled().unwrap());
+    driver.free().done();
+}
+
+#[test]
+fn output_discharge_keeps_oe() {
+    let expectations = [
+        // OE | HICCUP -> add DISCHG, OE must survive.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b1011_0000]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1011_0000]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1011_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b1010_0000]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_output_discharge(true).unwrap();
+    assert!(driver.get_output_discharge().unwrap());
+    driver.set_output_discharge(false).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn frequency_doubling_toggles_fswdbl_only() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b1110_0000]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1110_0000]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1110_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b1010_0000]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_frequency_doubling(true).unwrap();
+    assert!(driver.get_frequency_doubling().unwrap());
+    driver.set_frequency_doubling(false).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn read_and_write_mode_config() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b1010_0011]),
+        I2cTrans::write(0x74, vec![0x06, 0b0010_0011]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut cfg = driver.read_mode_config().unwrap();
+    assert!(cfg.output_enabled);
+    assert!(cfg.hiccup);
+    assert_eq!(cfg.light_load_override, LightLoadOverride::FromRegister);
+    assert_eq!(cfg.light_load_mode, LightLoadMode::Pwm);
+    cfg.output_enabled = false;
+    driver.write_mode_config(&cfg).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn init_with_config_orders_writes() {
+    let cfg = Config::new()
+        .vout_mv(12_000)
+        .ilim_ma(3_000, true)
+        .feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_0564)
+        .slew_rate(VoutSlewRate::Sr5MvPerUs)
+        .ocp_delay(OcpDelay::Ms3_072)
+        .cable_comp(CableCompOption::Internal, CableCompLevel::V0p2)
+        .fault_masks(true, true, true)
+        .light_load_mode(LightLoadMode::Pwm)
+        .enable_output(true);
+    let expectations = [
+        // CDC first: SC_MASK | OVP_MASK | level 0b010, OCP_MASK held clear while EN/OE rise
+        I2cTrans::write(0x74, vec![0x05, 0b1010_0010]),
+        // IOUT_LIMIT: EN | 60 (3000 mA / 50 mA)
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+        // VOUT_FS: internal, 0.0564
+        I2cTrans::write(0x74, vec![0x04, 0x03]),
+        // VOUT_SR: OCP_DELAY=01, SR=10
+        I2cTrans::write(0x74, vec![0x03, 0b0001_0010]),
+        // REF: 12 V -> 560 (0x0230)
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x02]),
+        // MODE: force FPWM (MODE | PFM)
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b0010_0000]),
+        I2cTrans::write(0x74, vec![0x06, 0b0010_0011]),
+        // MODE: OE last
+        I2cTrans::write_read(0x74, vec![0x06], vec![0b0010_0011]),
+        I2cTrans::write(0x74, vec![0x06, 0b1010_0011]),
+        // CDC: OCP_MASK set once OE and EN are on
+        I2cTrans::write(0x74, vec![0x05, 0b1110_0010]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.init_with_config(&cfg).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn init_with_default_config_leaves_oe_and_mode_untouched() {
+    let expectations = [
+        // CDC with OCP_MASK cleared before the limit is enabled.
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x02, 0xE4]),
+        I2cTrans::write(0x74, vec![0x04, 0x03]),
+        I2cTrans::write(0x74, vec![0x03, 0x01]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        // OCP_MASK restored last.
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.init_with_config(&Config::default()).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn set_vout_checked_enforces_inclusive_limits() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x00, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xFF, 0x03]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert!(matches!(
+        driver.set_vout_mv_checked(799),
+        Err(Error::OutOfRange)
+    ));
+    driver.set_vout_mv_checked(800).unwrap();
+    driver.set_vout_mv_checked(21_260).unwrap();
+    for mv in [21_280, 21_460, 21_480] {
+        assert!(matches!(
+            driver.set_vout_mv_checked(mv),
+            Err(Error::OutOfRange)
+        ));
+    }
+    driver.free().done();
+}
+
+#[test]
+fn set_ilim_checked_rejects_above_max() {
+    let expectations = [I2cTrans::write(0x74, vec![0x02, 0x80 | 127])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_ilim_ma_checked(6_350, true).unwrap();
+    assert!(matches!(
+        driver.set_ilim_ma_checked(6_351, true),
+        Err(Error::OutOfRange)
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn set_ilim_checked_rejects_below_useful_minimum() {
+    let expectations = [I2cTrans::write(0x74, vec![0x02, 0x80 | 4])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    for ma in [0, 50, 199] {
+        assert!(matches!(
+            driver.set_ilim_ma_checked(ma, true),
+            Err(Error::OutOfRange)
+        ));
+    }
+    driver.set_ilim_ma_checked(200, true).unwrap();
+    driver.free().done();
+
+    // The floor is a sense voltage: 2 mV is 100 mA across 20 mΩ and 400 mA across 5 mΩ.
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 4]),
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 4]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock).with_sense_resistor(20);
+    assert!(driver.set_ilim_ma_checked(99, true).is_err());
+    driver.set_ilim_ma_checked(100, true).unwrap();
+    let mut driver = Tps55288::new(driver.free()).with_sense_resistor(5);
+    assert!(driver.set_ilim_ma_checked(200, true).is_err());
+    driver.set_ilim_ma_checked(400, true).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn disable_current_limit_keeps_code_for_reenable() {
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 60]),
+        I2cTrans::write(0x74, vec![0x02, 60]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![60]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![60]),
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_ilim_ma(3_000, true).unwrap();
+    driver.disable_current_limit_keep_setting().unwrap();
+    assert_eq!(driver.get_ilim_ma().unwrap(), (3_000, false));
+    driver.enable_current_limit(true).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn ilim_from_sense_roundtrip() {
+    let expectations = [
+        // 2 A with 15 mΩ -> 30 mV -> code 60, enabled.
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_ilim_from_sense(2_000, 15, true).unwrap();
+    assert_eq!(driver.get_ilim_from_sense(15).unwrap(), (2_000, true));
+    assert!(matches!(
+        driver.set_ilim_from_sense(2_000, 0, true),
+        Err(Error::InvalidConfig)
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn vout_code_raw_masks_to_10_bits() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xFF, 0x03]),
+        // 0xFFFF is masked down to 0x03FF.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x03]),
+        I2cTrans::write(0x74, vec![0x00, 0xFF, 0x03]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x03]),
+        I2cTrans::write(0x74, vec![0x00, 0x34, 0x02]),
+        // Reserved REF1 bits are ignored on read.
+        I2cTrans::write_read(0x74, vec![0x00], vec![0x34, 0xFE]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_vout_code(0x03FF).unwrap();
+    driver.set_vout_code(0xFFFF).unwrap();
+    driver.set_vout_code(0x0634).unwrap();
+    assert_eq!(driver.get_vout_code().unwrap(), 0x0234);
+    driver.free().done();
+}
+
+#[test]
+fn external_vout_writes_ref_code() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xFF, 0x03]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_external_vout_mv(5_000, 100_000, 31_600).unwrap();
+    assert!(matches!(
+        driver.set_external_vout_mv(5_000, 100_000, 0),
+        Err(Error::InvalidConfig)
+    ));
+    driver.free().done();
+}
+
+struct CountingDelay {
+    total_ns: u64,
+}
+
+impl embedded_hal::delay::DelayNs for CountingDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += ns as u64;
+    }
+}
+
+#[test]
+fn ramp_vout_rising_and_falling() {
+    let expectations = [
+        // Rising 5000 -> 5050 mV in 20 mV steps: 5000, 5020, 5040, 5050.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 210, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 211, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 212, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 212, 0x00]),
+        // Falling 5050 -> 5000 mV in 30 mV steps: 5050, 5020, 5000.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 212, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 211, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 210, 0x00]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay { total_ns: 0 };
+    driver
+        .ramp_vout_mv(5_000, 5_050, 20, 100, &mut delay)
+        .unwrap();
+    assert_eq!(delay.total_ns, 3 * 100_000);
+    driver
+        .ramp_vout_mv(5_050, 5_000, 30, 100, &mut delay)
+        .unwrap();
+    assert_eq!(delay.total_ns, 5 * 100_000);
+    assert!(matches!(
+        driver.ramp_vout_mv(5_000, 5_050, 0, 100, &mut delay),
+        Err(Error::InvalidConfig)
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn read_fault_status_reports_ocp() {
+    let expectations = [I2cTrans::write_read(0x74, vec![0x07], vec![0x42])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let faults = driver.read_fault_status().unwrap();
+    assert!(faults.over_current && faults.any());
+    driver.free().done();
+}
+
+#[test]
+fn wait_for_fault_clear_recovers_on_second_poll() {
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x07, 0xE0]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x42]),
+        I2cTrans::write(0x74, vec![0x07, 0xE0]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x02]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay { total_ns: 0 };
+    driver.wait_for_fault_clear(3, 1_000, &mut delay).unwrap();
+    assert_eq!(delay.total_ns, 1_000_000);
+    driver.free().done();
+}
+
+#[test]
+fn wait_for_fault_clear_gives_up() {
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x07, 0xE0]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x80]),
+        I2cTrans::write(0x74, vec![0x07, 0xE0]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x80]),
+        I2cTrans::write(0x74, vec![0x07, 0xE0]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x80]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay { total_ns: 0 };
+    assert!(matches!(
+        driver.wait_for_fault_clear(2, 1_000, &mut delay),
+        Err(Error::FaultPersists)
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn probe_maps_nack_to_false() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x00])
+            .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x00]).with_error(ErrorKind::Bus),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert!(driver.probe().unwrap());
+    assert!(!driver.probe().unwrap());
+    assert!(matches!(driver.probe(), Err(Error::I2c(ErrorKind::Bus))));
+    driver.free().done();
+}
+
+#[test]
+fn detect_skips_nacking_address() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x00])
+            .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        I2cTrans::write_read(0x75, vec![0x06], vec![0x24]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let Ok(driver) = Tps55288::detect_default(mock) else {
+        panic!("detect failed");
+    };
+    assert_eq!(driver.address(), 0x75);
+    driver.free().done();
+}
+
+#[test]
+fn detect_reports_no_device() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x00]).with_error(nack),
+        I2cTrans::write_read(0x75, vec![0x06], vec![0x00]).with_error(nack),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let Err((mut mock, err)) = Tps55288::detect_default(mock) else {
+        panic!("detect should fail");
+    };
+    assert!(matches!(err, Error::NoDevice));
+    mock.done();
+}
+
+#[test]
+fn get_feedback_decodes_vout_fs() {
+    use tps55288::data_types::{FeedbackSource, InternalFeedbackRatio};
+
+    let cases = [
+        (
+            0x00,
+            FeedbackSource::Internal,
+            InternalFeedbackRatio::R0_2256,
+        ),
+        (
+            0x01,
+            FeedbackSource::Internal,
+            InternalFeedbackRatio::R0_1128,
+        ),
+        (
+            0x02,
+            FeedbackSource::Internal,
+            InternalFeedbackRatio::R0_0752,
+        ),
+        (
+            0x03,
+            FeedbackSource::Internal,
+            InternalFeedbackRatio::R0_0564,
+        ),
+        (
+            0x81,
+            FeedbackSource::External,
+            InternalFeedbackRatio::R0_1128,
+        ),
+    ];
+    let expectations: Vec<_> = cases
+        .iter()
+        .map(|(raw, _, _)| I2cTrans::write_read(0x74, vec![0x04], vec![*raw]))
+        .collect();
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    for (_, source, ratio) in cases {
+        assert_eq!(driver.get_feedback().unwrap(), (source, ratio));
+        assert_eq!(driver.feedback_ratio(), ratio);
+    }
+    driver.free().done();
+}
+
+#[test]
+fn cable_comp_roundtrip_all_levels() {
+    use tps55288::data_types::{CableCompLevel, CableCompOption};
+
+    let levels = [
+        CableCompLevel::V0p0,
+        CableCompLevel::V0p1,
+        CableCompLevel::V0p2,
+        CableCompLevel::V0p3,
+        CableCompLevel::V0p4,
+        CableCompLevel::V0p5,
+        CableCompLevel::V0p6,
+        CableCompLevel::V0p7,
+    ];
+    let masks = [
+        (true, true, true),
+        (false, true, false),
+        (true, false, false),
+    ];
+    let mut cases = Vec::new();
+    for (i, level) in levels.into_iter().enumerate() {
+        let option = if i % 2 == 0 {
+            CableCompOption::Internal
+        } else {
+            CableCompOption::External
+        };
+        let (sc, ocp, ovp) = masks[i % masks.len()];
+        let raw =
+            (sc as u8) << 7 | (ocp as u8) << 6 | (ovp as u8) << 5 | (i as u8 % 2) << 3 | i as u8;
+        cases.push((raw, option, level, sc, ocp, ovp));
+    }
+    let mut expectations = Vec::new();
+    for (raw, ..) in &cases {
+        expectations.push(I2cTrans::write(0x74, vec![0x05, *raw]));
+        expectations.push(I2cTrans::write_read(0x74, vec![0x05], vec![*raw]));
+    }
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    for (_, option, level, sc, ocp, ovp) in cases {
+        driver.set_cable_comp(option, level, sc, ocp, ovp).unwrap();
+        assert_eq!(
+            driver.get_cable_comp().unwrap(),
+            (option, level, sc, ocp, ovp)
+        );
+    }
+    driver.free().done();
+}
+
+#[test]
+fn vout_sr_roundtrip_all_combinations() {
+    use tps55288::data_types::{OcpDelay, VoutSlewRate};
+
+    let slews = [
+        VoutSlewRate::Sr1p25MvPerUs,
+        VoutSlewRate::Sr2p5MvPerUs,
+        VoutSlewRate::Sr5MvPerUs,
+        VoutSlewRate::Sr10MvPerUs,
+    ];
+    let delays = [
+        OcpDelay::Us128,
+        OcpDelay::Ms3_072,
+        OcpDelay::Ms6_144,
+        OcpDelay::Ms12_288,
+    ];
+    let mut expectations = Vec::new();
+    for d in 0u8..4 {
+        for s in 0u8..4 {
+            let raw = d << 4 | s;
+            expectations.push(I2cTrans::write(0x74, vec![0x03, raw]));
+            expectations.push(I2cTrans::write_read(0x74, vec![0x03], vec![raw]));
+        }
+    }
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    for delay in delays {
+        for slew in slews {
+            driver.set_vout_sr(slew, delay).unwrap();
+            assert_eq!(driver.get_vout_sr().unwrap(), (slew, delay));
+        }
+    }
+    driver.free().done();
+}
+
+#[test]
+fn read_all_decodes_snapshot() {
+    use tps55288::data_types::{LightLoadMode, OperatingStatus};
+
+    // REF=0x03C0, ILIM 3 A enabled, SR 5 mV/us, VOUT_FS 0.0564, CDC reset, MODE OE|HICCUP|PFM|MODE,
+    // STATUS OCP + buck-boost.
+    let blob = vec![0xC0, 0x03, 0x80 | 60, 0x02, 0x03, 0xE0, 0xA3, 0x42];
+    let expectations = [I2cTrans::write_read(0x74, vec![0x00], blob.clone())];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let snap = driver.read_all().unwrap();
+    assert_eq!(snap.to_bytes().to_vec(), blob);
+    assert_eq!(snap.vout_code(), 0x03C0);
+    assert_eq!(snap.ilim(), (3_000, true));
+    let mode = snap.mode_config();
+    assert!(mode.output_enabled && mode.hiccup && !mode.discharge);
+    assert_eq!(mode.light_load_mode, LightLoadMode::Pwm);
+    let (op, faults) = snap.status();
+    assert_eq!(op, OperatingStatus::BuckBoost);
+    assert!(faults.over_current && !faults.short_circuit && !faults.over_voltage);
+    driver.free().done();
+}
+
+#[test]
+fn reset_to_defaults_write_sequence() {
+    use tps55288::data_types::{FeedbackSource, InternalFeedbackRatio};
+
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x04, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xB3]),
+        I2cTrans::write(0x74, vec![0x06, 0x33]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0]),
+        I2cTrans::write(0x74, vec![0x06, 0x20]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver
+        .set_feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_2256)
+        .unwrap();
+    driver.reset_to_defaults().unwrap();
+    assert_eq!(driver.feedback_ratio(), InternalFeedbackRatio::R0_0564);
+    driver.free().done();
+}
+
+#[test]
+fn free_returns_bus_for_other_devices() {
+    use embedded_hal::i2c::I2c;
+
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x06, 0x20]),
+        // Another device on the same bus after the driver releases it.
+        I2cTrans::write(0x3C, vec![0xAE]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.write_reg(0x06, 0x20).unwrap();
+    let mut bus = driver.free();
+    bus.write(0x3C, &[0xAE]).unwrap();
+    bus.done();
+}
+
+#[test]
+fn root_reexports_match_module_paths() {
+    // The package, lib target and every test/example import use the single name `tps55288`.
+    let mock = I2cMock::new(&[]);
+    let driver: tps55288::Tps55288<_> = Tps55288::with_address(mock, tps55288::DEFAULT_I2C_ADDRESS);
+    let driver: tps55288::driver::Tps55288<_> = driver;
+    driver.free().done();
+}
+
+#[test]
+fn write_config_burst_single_transaction() {
+    let cfg = Config::new()
+        .vout_mv(9_000)
+        .ilim_ma(3_000, true)
+        .feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_1128)
+        .slew_rate(VoutSlewRate::Sr5MvPerUs)
+        .ocp_delay(OcpDelay::Ms3_072)
+        .cable_comp(CableCompOption::External, CableCompLevel::V0p2)
+        .fault_masks(true, false, true)
+        .enable_output(true);
+    let expectations = [
+        // 9 V on 0.1128: (9000 - 400) / 10 = 860 = 0x035C.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x05, 0xAA]),
+        I2cTrans::write(0x74, vec![0x00, 0x5C, 0x03, 0x80 | 60, 0x12, 0x01, 0xAA]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.write_config_burst(&cfg).unwrap();
+    assert_eq!(driver.feedback_ratio(), InternalFeedbackRatio::R0_1128);
+    driver.free().done();
+}
+
+#[test]
+fn change_vout_waits_for_slew() {
+    let expectations = [
+        // Currently 5 V (code 210), moving to 9 V (code 410) at 2.5 mV/us -> 1600 us.
+        I2cTrans::write_read(0x74, vec![0x00], vec![210, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x9A, 0x01]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay { total_ns: 0 };
+    driver
+        .change_vout_and_wait(9_000, VoutSlewRate::Sr2p5MvPerUs, &mut delay)
+        .unwrap();
+    assert_eq!(delay.total_ns, 1_600_000);
+    driver.free().done();
+}
+
+#[test]
+fn select_address_for_preset_targets_boot_address() {
+    use tps55288::data_types::MODE_PRESETS;
+
+    let expectations = [I2cTrans::write_read(0x75, vec![0x06], vec![0x20])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.select_address_for_preset(&MODE_PRESETS[3]);
+    assert_eq!(driver.read_reg(0x06).unwrap(), 0x20);
+    driver.free().done();
+}
+
+#[test]
+fn set_software_address_writes_old_then_uses_new() {
+    use tps55288::data_types::I2cAddress;
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0x25]),
+        I2cTrans::write_read(0x75, vec![0x07], vec![0x03]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_software_address(I2cAddress::Addr0x75).unwrap();
+    assert_eq!(driver.address(), 0x75);
+    driver.read_status_raw().unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn apply_preset_overrides_writes_mode_trio() {
+    use tps55288::data_types::MODE_PRESETS;
+
+    let expectations = [
+        // 24.9 kΩ: internal VCC, 0x75, PFM -> MODE | I2CADD.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+        I2cTrans::write(0x74, vec![0x06, 0xA5]),
+        // 51.1 kΩ: external 5 V, 0x74, FPWM -> MODE | PFM | VCC, written to 0x75.
+        I2cTrans::write_read(0x75, vec![0x06], vec![0xA5]),
+        I2cTrans::write(0x75, vec![0x06, 0xAB]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.apply_preset_overrides(&MODE_PRESETS[3]).unwrap();
+    assert_eq!(driver.address(), 0x75);
+    driver.apply_preset_overrides(&MODE_PRESETS[4]).unwrap();
+    assert_eq!(driver.address(), 0x74);
+    driver.free().done();
+}
+
+#[test]
+fn is_output_enabled_reads_oe() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert!(driver.is_output_enabled().unwrap());
+    assert!(!driver.is_output_enabled().unwrap());
+    driver.free().done();
+}
+
+#[test]
+fn status_reserved_bits_survive_decode() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x5E]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x5E]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let bits = driver.read_status_raw().unwrap();
+    assert_eq!(bits.bits(), 0x5E);
+    assert_eq!(bits.raw_reserved(), 0x1C);
+    let (op, faults) = driver.read_status().unwrap();
+    assert_eq!(op, OperatingStatus::BuckBoost);
+    assert!(faults.over_current && !faults.short_circuit && !faults.over_voltage);
+    driver.free().done();
+}
+
+/// Shared event log so the relative order of delays and bus traffic can be asserted.
+type EventLog = std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>;
+
+struct LoggingI2c {
+    inner: I2cMock,
+    log: EventLog,
+}
+
+impl embedded_hal::i2c::ErrorType for LoggingI2c {
+    type Error = embedded_hal::i2c::ErrorKind;
+}
+
+impl embedded_hal::i2c::I2c for LoggingI2c {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push("i2c");
+        self.inner.transaction(address, operations)
+    }
+
+    fn write(&mut self, address: u8, write: &[u8]) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push("i2c");
+        self.inner.write(address, write)
+    }
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push("i2c");
+        self.inner.write_read(address, write, read)
+    }
+}
+
+struct LoggingDelay {
+    log: EventLog,
+}
+
+impl embedded_hal::delay::DelayNs for LoggingDelay {
+    fn delay_ns(&mut self, _ns: u32) {
+        self.log.borrow_mut().push("delay");
+    }
+}
+
+#[test]
+fn start_waits_configures_then_enables() {
+    let expectations = [
+        // CDC with OCP_MASK cleared before the limit is enabled.
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x02, 0xE4]),
+        I2cTrans::write(0x74, vec![0x04, 0x03]),
+        I2cTrans::write(0x74, vec![0x03, 0x01]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+        // OCP_MASK only after OE is confirmed.
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+    ];
+    let log = EventLog::default();
+    let bus = LoggingI2c {
+        inner: I2cMock::new(&expectations),
+        log: log.clone(),
+    };
+    let mut delay = LoggingDelay { log: log.clone() };
+    let mut driver = Tps55288::new(bus);
+    driver.start(1_000, &mut delay, &Config::default()).unwrap();
+    let events = log.borrow();
+    assert_eq!(events[0], "delay");
+    assert!(events[1..].iter().all(|e| *e == "i2c"));
+    drop(events);
+    driver.free().inner.done();
+}
+
+#[test]
+fn start_reports_mode_readback_mismatch() {
+    let expectations = [
+        // CDC with OCP_MASK cleared before the limit is enabled.
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x02, 0xE4]),
+        I2cTrans::write(0x74, vec![0x04, 0x03]),
+        I2cTrans::write(0x74, vec![0x03, 0x01]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay { total_ns: 0 };
+    assert!(matches!(
+        driver.start(1_000, &mut delay, &Config::default()),
+        Err(Error::Mismatch {
+            reg: 0x06,
+            expected: 0xA0,
+            actual: 0x20
+        })
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn verify_config_matches_and_reports_first_mismatch() {
+    let cfg = Config::new()
+        .vout_mv(12_000)
+        .light_load_mode(LightLoadMode::Pwm)
+        .enable_output(true);
+    // REF 0x0230, reset ILIM/SR/FS/CDC, MODE OE|HICCUP|PFM|MODE.
+    let good = vec![0x30, 0x02, 0xE4, 0x01, 0x03, 0xE0, 0xA3];
+    let mut bad = good.clone();
+    bad[3] = 0x02;
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x00], good),
+        I2cTrans::write_read(0x74, vec![0x00], bad),
+        // OE dropped.
+        I2cTrans::write_read(
+            0x74,
+            vec![0x00],
+            vec![0x30, 0x02, 0xE4, 0x01, 0x03, 0xE0, 0x23],
+        ),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.verify_config(&cfg).unwrap();
+    assert!(matches!(
+        driver.verify_config(&cfg),
+        Err(Error::Mismatch {
+            reg: 0x03,
+            expected: 0x01,
+            actual: 0x02
+        })
+    ));
+    assert!(matches!(
+        driver.verify_config(&cfg),
+        Err(Error::Mismatch {
+            reg: 0x06,
+            expected: 0xA3,
+            actual: 0x23
+        })
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn typed_unit_setters_match_raw() {
+    use tps55288::data_types::{Milliamps, Millivolts};
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x02]),
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_vout(Millivolts(12_000)).unwrap();
+    driver.set_ilim(Milliamps(3_000), true).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn set_fault_masks_preserves_droop_level() {
+    let expectations = [
+        // CDC_OPT=1, level=0b101, all masks on -> mask only OVP off.
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b1110_1101]),
+        I2cTrans::write(0x74, vec![0x05, 0b1100_1101]),
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b1100_1101]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_fault_masks(true, true, false).unwrap();
+    assert_eq!(driver.get_fault_masks().unwrap(), (true, true, false));
+    driver.free().done();
+}
+
+#[test]
+fn set_cable_comp_level_preserves_masks() {
+    let expectations = [
+        // SC and OVP masks on, OCP off, internal 0.0 V -> external 0.3 V.
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b1010_0000]),
+        I2cTrans::write(0x74, vec![0x05, 0b1010_1011]),
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b1010_1011]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver
+        .set_cable_comp_level(CableCompOption::External, CableCompLevel::V0p3)
+        .unwrap();
+    assert_eq!(driver.get_fault_masks().unwrap(), (true, false, true));
+    driver.free().done();
+}
+
+#[test]
+fn typed_register_access_uses_enum_address() {
+    use tps55288::registers::Register;
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x03]),
+        I2cTrans::write(0x74, vec![0x03, 0x21]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert_eq!(driver.read_register(Register::Status).unwrap(), 0x03);
+    driver.write_register(Register::VoutSr, 0x21).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn status_watcher_reports_only_changes() {
+    use tps55288::StatusWatcher;
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x02]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x02]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x42]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut watcher = StatusWatcher::new(Tps55288::new(mock));
+    let first = watcher.poll_change().unwrap();
+    assert!(first.is_some());
+    assert_eq!(watcher.poll_change().unwrap(), None);
+    let (_, faults) = watcher.poll_change().unwrap().unwrap();
+    assert!(faults.over_current);
+    watcher.into_inner().free().done();
+}
+
+#[test]
+fn on_interrupt_returns_latched_faults() {
+    let expectations = [I2cTrans::write_read(0x74, vec![0x07], vec![0b1010_0010])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let faults = driver.on_interrupt().unwrap();
+    assert!(faults.short_circuit);
+    assert!(!faults.over_current);
+    assert!(faults.over_voltage);
+    driver.free().done();
+}
+
+#[test]
+fn slew_rate_and_ocp_delay_update_independently() {
+    let expectations = [
+        // OCP delay 6.144 ms kept while slew moves to 10 mV/us.
+        I2cTrans::write_read(0x74, vec![0x03], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x03, 0x23]),
+        // Slew 10 mV/us kept while OCP delay moves to 3.072 ms.
+        I2cTrans::write_read(0x74, vec![0x03], vec![0x23]),
+        I2cTrans::write(0x74, vec![0x03, 0x13]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_slew_rate(VoutSlewRate::Sr10MvPerUs).unwrap();
+    driver.set_ocp_delay(OcpDelay::Ms3_072).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn sense_resistor_scales_milliamp_limits() {
+    let expectations = [
+        // 3 A × 10 mΩ = 30 mV -> code 60.
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert_eq!(driver.sense_resistor_mohm(), 10);
+    driver.set_ilim_ma(3_000, true).unwrap();
+    assert_eq!(driver.get_ilim_ma().unwrap(), (3_000, true));
+    driver.free().done();
+
+    let expectations = [
+        // 3 A × 5 mΩ = 15 mV -> code 30.
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 30]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 30]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock).with_sense_resistor(5);
+    driver.set_ilim_ma(3_000, true).unwrap();
+    assert_eq!(driver.get_ilim_ma().unwrap(), (3_000, true));
+    // 63.5 mV / 5 mΩ = 12.7 A is now reachable.
+    assert!(driver.set_ilim_ma_checked(12_800, true).is_err());
+    driver.free().done();
+}
+
+#[test]
+fn set_vout_and_confirm_reports_post_step_status() {
+    let expectations = [
+        // 3.3 V (code 125) -> 12 V (code 560) at 2.5 mV/us -> 3480 us, then boost.
+        I2cTrans::write_read(0x74, vec![0x00], vec![125, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x02]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x00]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mut delay = CountingDelay { total_ns: 0 };
+    let (mode, faults) = driver
+        .set_vout_and_confirm(12_000, VoutSlewRate::Sr2p5MvPerUs, &mut delay)
+        .unwrap();
+    assert_eq!(delay.total_ns, 3_480_000);
+    assert_eq!(mode, OperatingStatus::Boost);
+    assert!(faults.is_clear());
+    driver.free().done();
+}
+
+#[test]
+fn vout_code_preserves_non_dac_ref1_bits() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x81]),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x82]),
+        // set_vout_mv: 9 V -> 0x019A.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x82]),
+        I2cTrans::write(0x74, vec![0x00, 0x9A, 0x81]),
+        // set_vout_mv_verified: 12 V -> 0x0230, readback includes bit 7.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x81]),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x82]),
+        I2cTrans::write_read(0x74, vec![0x00], vec![0x30, 0x82]),
+        // write_config_burst: default 5 V -> 0x00D2.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x82]),
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x80, 0xE4, 0x01, 0x03, 0xA0]),
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+        // verify_config compares against the preserved bit.
+        I2cTrans::write_read(
+            0x74,
+            vec![0x00],
+            vec![0xD2, 0x80, 0xE4, 0x01, 0x03, 0xE0, 0x20],
+        ),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.set_vout_code(0x0230).unwrap();
+    driver.set_vout_mv(9_000).unwrap();
+    driver.set_vout_mv_verified(12_000).unwrap();
+    driver.write_config_burst(&Config::default()).unwrap();
+    driver.verify_config(&Config::default()).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn check_identity_accepts_reset_state_and_rejects_garbage() {
+    let expectations = [
+        I2cTrans::write_read(
+            0x74,
+            vec![0x00],
+            vec![0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20],
+        ),
+        I2cTrans::write_read(0x74, vec![0x00], vec![0xFF; 7]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.check_identity().unwrap();
+    assert!(matches!(driver.check_identity(), Err(Error::InvalidConfig)));
+    driver.free().done();
+}
+
+#[test]
+fn rebind_keeps_address_and_cached_state() {
+    let first = I2cMock::new(&[]);
+    let driver = Tps55288::with_address(first.clone(), 0x75).with_sense_resistor(5);
+
+    let expectations = [I2cTrans::write_read(0x75, vec![0x06], vec![0x20])];
+    let second = I2cMock::new(&expectations);
+    let mut driver = driver.rebind(second);
+    assert_eq!(driver.address(), 0x75);
+    assert_eq!(driver.sense_resistor_mohm(), 5);
+    assert_eq!(driver.read_reg(0x06).unwrap(), 0x20);
+    driver.free().done();
+    let mut first = first;
+    first.done();
+}
+
+#[test]
+fn check_faults_maps_status_to_errors() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x01]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x80]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x40]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x20]),
+        // SCP wins when several faults are latched.
+        I2cTrans::write_read(0x74, vec![0x07], vec![0xE0]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.check_faults().unwrap();
+    assert!(matches!(driver.check_faults(), Err(Error::ShortCircuit)));
+    assert!(matches!(driver.check_faults(), Err(Error::OverCurrent)));
+    assert!(matches!(driver.check_faults(), Err(Error::OverVoltage)));
+    assert!(matches!(driver.check_faults(), Err(Error::ShortCircuit)));
+    driver.free().done();
+}
+
+#[test]
+fn unconfigured_driver_configures_then_enables() {
+    let expectations = [
+        // CDC with OCP_MASK cleared before the limit is enabled.
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x02, 0xE4]),
+        I2cTrans::write(0x74, vec![0x04, 0x03]),
+        I2cTrans::write(0x74, vec![0x03, 0x01]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let driver = Tps55288::unconfigured(mock);
+    let Ok(mut driver) = driver.configure(&Config::default()) else {
+        panic!("configure failed");
+    };
+    driver.enable_output().unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn unconfigured_driver_survives_failed_configure() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let expectations = [I2cTrans::write(0x74, vec![0x05, 0xA0])
+        .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data))];
+    let mock = I2cMock::new(&expectations);
+    let Err((driver, err)) = Tps55288::unconfigured(mock).configure(&Config::default()) else {
+        panic!("configure should fail");
+    };
+    assert!(matches!(err, Error::I2c(ErrorKind::NoAcknowledge(_))));
+    driver.free().done();
+}
+
+#[test]
+fn unconfigured_driver_can_be_forced() {
+    let expectations = [I2cTrans::write_read(0x74, vec![0x06], vec![0xA0])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::unconfigured(mock).force();
+    assert!(driver.is_output_enabled().unwrap());
+    driver.free().done();
+}
+
+#[test]
+fn write_regs_reports_buffer_overflow() {
+    let expectations = [I2cTrans::write(
+        0x74,
+        vec![0x00, 0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20],
+    )];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver
+        .write_regs(0x00, &[0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20])
+        .unwrap();
+    assert!(matches!(
+        driver.write_regs(0x00, &[0u8; 8]),
+        Err(Error::BufferTooSmall {
+            needed: 9,
+            capacity: 8
+        })
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn write_regs_streaming_covers_the_whole_map() {
+    let image = [0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20, 0x00];
+    let expectations = [
+        I2cTrans::transaction_start(0x74),
+        I2cTrans::write(0x74, vec![0x00]),
+        I2cTrans::write(0x74, image.to_vec()),
+        I2cTrans::transaction_end(0x74),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.write_regs_streaming(0x00, &image).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn atomic_set_and_read_status_uses_one_transaction() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::transaction_start(0x74),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x02]),
+        I2cTrans::read(0x74, vec![0xE4]),
+        I2cTrans::write(0x74, vec![0x07]),
+        I2cTrans::read(0x74, vec![0x40]),
+        I2cTrans::transaction_end(0x74),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let (mode, faults) = driver.atomic_set_and_read_status(12_000).unwrap();
+    assert_eq!(mode, OperatingStatus::Boost);
+    assert!(faults.over_current);
+    driver.free().done();
+}
+
+#[test]
+fn ilim_value_and_enable_update_independently() {
+    let expectations = [
+        // Enabled at 5 A; move to 3 A and stay enabled.
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 100]),
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+        // Disable without touching the 3 A code.
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 60]),
+        I2cTrans::write(0x74, vec![0x02, 60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver.update_ilim_ma(3_000).unwrap();
+    driver.enable_current_limit(false).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn read_report_reads_mode_and_status_together() {
+    let expectations = [I2cTrans::write_read(0x74, vec![0x06], vec![0x20, 0xA2])];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let report = driver.read_report().unwrap();
+    assert_eq!(
+        format!("{report}"),
+        "mode=BuckBoost faults=SCP+OVP oe=off light_load=PFM(pin) vcc=internal LDO addr=0x74"
+    );
+    // Fields render exactly as their own types do.
+    let line = format!("{report}");
+    assert!(line.contains(&format!("vcc={} ", report.vcc_source)));
+    assert!(line.ends_with(&format!(
+        "addr={}",
+        tps55288::data_types::I2cAddress::Addr0x74
+    )));
+    driver.free().done();
+}
+
+#[test]
+fn read_status_strict_rejects_reserved_mode() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0b0000_0011]),
+        // OE set: 0b11 is unexpected.
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0b0000_0011]),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0b0000_0001]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert!(matches!(
+        driver.read_status_strict(),
+        Err(Error::UnexpectedStatus(0x03))
+    ));
+    // The lenient reader still decodes it.
+    assert_eq!(driver.read_status().unwrap().0, OperatingStatus::Reserved);
+    assert_eq!(
+        driver.read_status_strict().unwrap().0,
+        OperatingStatus::Buck
+    );
+    driver.free().done();
+}
+
+#[test]
+fn read_status_strict_accepts_reset_value_with_oe_off() {
+    let expectations = [
+        // STATUS and MODE reset values straight after power-up.
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x03]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let (operating, faults) = driver.read_status_strict().unwrap();
+    assert!(operating.is_transitioning());
+    assert!(faults.is_clear());
+    driver.free().done();
+}
+
+#[test]
+fn shutdown_discharges_before_dropping_oe() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+        // DISCHG on, OE still set.
+        I2cTrans::write(0x74, vec![0x06, 0xB0]),
+        // OE off with DISCHG held.
+        I2cTrans::write(0x74, vec![0x06, 0x30]),
+        // DISCHG restored after the wait.
+        I2cTrans::write(0x74, vec![0x06, 0x20]),
+    ];
+    let log = EventLog::default();
+    let bus = LoggingI2c {
+        inner: I2cMock::new(&expectations),
+        log: log.clone(),
+    };
+    let mut delay = LoggingDelay { log: log.clone() };
+    let mut driver = Tps55288::new(bus);
+    driver.shutdown(50, &mut delay).unwrap();
+    let events = log.borrow();
+    let (last, rest) = events.split_last().unwrap();
+    assert_eq!(&rest[..3], ["i2c"; 3]);
+    assert!(!rest[3..].is_empty() && rest[3..].iter().all(|e| *e == "delay"));
+    assert_eq!(*last, "i2c");
+    drop(events);
+    driver.free().inner.done();
+}
+
+#[test]
+fn get_current_limit_hides_disabled_setting() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x02], vec![0x80 | 60]),
+        I2cTrans::write_read(0x74, vec![0x02], vec![60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    assert_eq!(driver.get_current_limit().unwrap(), Some(3_000));
+    assert_eq!(driver.get_current_limit().unwrap(), None);
+    driver.free().done();
+}
+
+#[test]
+fn mode_flags_round_trip() {
+    use tps55288::registers::ModeBits;
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA3]),
+        I2cTrans::write(0x74, vec![0x06, 0xA3]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    let mode = driver.read_mode().unwrap();
+    assert!(mode.contains(ModeBits::OE | ModeBits::HICCUP | ModeBits::PFM | ModeBits::MODE));
+    assert_eq!(mode.bits(), 0xA3);
+    driver.write_mode(mode).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn set_vout_mv_verified_accepts_matching_readback() {
+    // 5 V at ratio 0.0564 -> code 210 (0x00D2).
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x00], vec![0xD2, 0x00]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.set_vout_mv_verified(5_000).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn set_vout_mv_verified_reports_flipped_byte() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x00], vec![0xD2, 0x02]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    assert!(matches!(
+        driver.set_vout_mv_verified(5_000),
+        Err(Error::Mismatch {
+            reg: 0x01,
+            expected: 0x00,
+            actual: 0x02
+        })
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn init_never_touches_mode_or_oe() {
+    // Only IOUT_LIMIT and REF are written; a MODE access would fail the mock.
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x02, 0xE4]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x00, 0x00]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.init().unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn vref_trim_shifts_vout_code() {
+    let expectations = [
+        // 5 V -> code 210; +40 mV trim -> 212.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD4, 0x00]),
+        // Trim never pushes the code past the DAC range.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xFF, 0x03]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x00, 0x00]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    assert_eq!(driver.vref_trim_mv(), 0);
+    driver.set_vref_trim_mv(40);
+    driver.set_vout_mv(5_000).unwrap();
+    driver.set_vout_mv(21_260).unwrap();
+    driver.set_vref_trim_mv(-100);
+    driver.set_vout_mv(800).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn vref_trim_reaches_config_paths() {
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x02, 0xE4]),
+        I2cTrans::write(0x74, vec![0x04, 0x03]),
+        I2cTrans::write(0x74, vec![0x03, 0x01]),
+        // 5 V + 40 mV -> code 212.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD4, 0x00]),
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+        I2cTrans::write_read(
+            0x74,
+            vec![0x00],
+            vec![0xD4, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20],
+        ),
+        // Burst write uses the same trimmed code.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x00, 0xD4, 0x00, 0xE4, 0x01, 0x03, 0xA0]),
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.set_vref_trim_mv(40);
+    driver.init_with_config(&Config::default()).unwrap();
+    driver.verify_config(&Config::default()).unwrap();
+    driver.write_config_burst(&Config::default()).unwrap();
+    // 21.24 V is in range on its own, but not after the trim.
+    assert!(matches!(
+        driver.set_vout_mv_checked(21_240),
+        Err(Error::OutOfRange)
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn apply_diff_writes_only_changed_registers() {
+    use tps55288::data_types::RegisterSnapshot;
+
+    let current = RegisterSnapshot::from_bytes([0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20, 0x03]);
+    let desired = RegisterSnapshot {
+        vout_sr: 0x23,
+        mode: 0xA0,
+        // STATUS differences are ignored.
+        status: 0x00,
+        ..current
+    };
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x03, 0x23]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.apply_diff(&current, &desired).unwrap();
+    driver.free().done();
+
+    // A change in either REF byte rewrites both.
+    let desired = RegisterSnapshot {
+        ref1: 0x01,
+        ..current
+    };
+    let expectations = [I2cTrans::write(0x74, vec![0x00, 0xD2, 0x01])];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.apply_diff(&current, &desired).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn dry_run_config_reads_without_writing() {
+    use tps55288::data_types::LightLoadMode;
+
+    let reset = vec![0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20];
+    let expectations = [
+        // Only REF0..MODE are read; STATUS is left latched.
+        I2cTrans::write_read(0x74, vec![0x00], reset.clone()),
+        I2cTrans::write_read(0x74, vec![0x00], reset.clone()),
+        // Writing the diff back touches only the changed registers.
+        I2cTrans::write(0x74, vec![0x00, 0x9A, 0x01]),
+        I2cTrans::write(0x74, vec![0x02, 0xBC]),
+        I2cTrans::write(0x74, vec![0x06, 0xA3]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let cfg = Config::new()
+        .vout_mv(9_000)
+        .ilim_ma(3_000, true)
+        .light_load_mode(LightLoadMode::Pwm)
+        .enable_output(true);
+
+    let target = driver.dry_run_config(&cfg).unwrap();
+    assert_eq!(
+        target.to_bytes(),
+        [0x9A, 0x01, 0xBC, 0x01, 0x03, 0xE0, 0xA3, 0x00]
+    );
+
+    let diff = driver.config_diff(&cfg).unwrap();
+    assert_eq!(diff.target, target);
+    assert_eq!(diff.current.to_bytes()[..7], reset[..]);
+    let changes: Vec<_> = diff.changes().collect();
+    assert_eq!(
+        changes,
+        [
+            (0x00, 0xD2, 0x9A),
+            (0x01, 0x00, 0x01),
+            (0x02, 0xE4, 0xBC),
+            (0x06, 0x20, 0xA3)
+        ]
+    );
+    assert_eq!(
+        format!("{diff}"),
+        "0x00: 0xD2 -> 0x9A, 0x01: 0x00 -> 0x01, 0x02: 0xE4 -> 0xBC, 0x06: 0x20 -> 0xA3"
+    );
+    driver.apply_diff(&diff.current, &diff.target).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn dry_run_config_matches_burst_write() {
+    let cfg = Config::new()
+        .vout_mv(9_000)
+        .slew_rate(VoutSlewRate::Sr10MvPerUs);
+    let expectations = [
+        I2cTrans::write_read(
+            0x74,
+            vec![0x00],
+            vec![0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20],
+        ),
+        // 9 V - 60 mV trim -> code 407.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x05, 0xA0]),
+        I2cTrans::write(0x74, vec![0x00, 0x97, 0x01, 0xE4, 0x03, 0x03, 0xA0]),
+        I2cTrans::write(0x74, vec![0x05, 0xE0]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.set_vref_trim_mv(-60);
+    let target = driver.dry_run_config(&cfg).unwrap();
+    assert_eq!(target.to_bytes()[..6], [0x97, 0x01, 0xE4, 0x03, 0x03, 0xE0]);
+    driver.write_config_burst(&cfg).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn default_config_on_reset_device_changes_nothing() {
+    let expectations = [I2cTrans::write_read(
+        0x74,
+        vec![0x00],
+        vec![0xD2, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20],
+    )];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let diff = driver.config_diff(&Config::default()).unwrap();
+    assert!(diff.is_empty());
+    assert_eq!(format!("{diff}"), "no changes");
+    driver.free().done();
+}
+
+#[test]
+fn enable_output_retry_recovers_from_nack() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]).with_error(nack),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let mut delay = CountingDelay { total_ns: 0 };
+    driver.enable_output_retry(2, 500, &mut delay).unwrap();
+    assert_eq!(delay.total_ns, 500_000);
+    driver.free().done();
+
+    // Out of retries: the last error is returned.
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]).with_error(nack),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]).with_error(ErrorKind::Bus),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    assert!(matches!(
+        driver.enable_output_retry(1, 500, &mut delay),
+        Err(Error::I2c(ErrorKind::Bus))
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn builder_sets_address_and_sense_resistor() {
+    use tps55288::Tps55288Builder;
+
+    let driver = Tps55288Builder::new()
+        .address(0x75)
+        .rsense_mohm(5)
+        .build(I2cMock::new(&[]));
+    assert_eq!(driver.address(), 0x75);
+    assert_eq!(driver.sense_resistor_mohm(), 5);
+    driver.free().done();
+
+    // 5 A across 5 mΩ -> 25 mV -> code 50, enabled.
+    let expectations = [
+        I2cTrans::write(0x75, vec![0x05, 0xA0]),
+        I2cTrans::write(0x75, vec![0x02, 0x80 | 50]),
+        I2cTrans::write(0x75, vec![0x04, 0x03]),
+        I2cTrans::write(0x75, vec![0x03, 0x01]),
+        I2cTrans::write_read(0x75, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x75, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write(0x75, vec![0x05, 0xE0]),
+    ];
+    let driver = Tps55288Builder::new()
+        .address(0x75)
+        .rsense_mohm(5)
+        .config(Config::default())
+        .build_and_init(I2cMock::new(&expectations))
+        .unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn get_vout_round_trips_at_every_ratio() {
+    // (ratio, VOUT_FS byte, code for 3 V)
+    let cases = [
+        (InternalFeedbackRatio::R0_2256, 0x00, 560u16),
+        (InternalFeedbackRatio::R0_1128, 0x01, 260),
+        (InternalFeedbackRatio::R0_0752, 0x02, 160),
+        (InternalFeedbackRatio::R0_0564, 0x03, 110),
+    ];
+    for (ratio, fs, code) in cases {
+        let [lo, hi] = code.to_le_bytes();
+        let expectations = [
+            I2cTrans::write(0x74, vec![0x04, fs]),
+            I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+            I2cTrans::write(0x74, vec![0x00, lo, hi]),
+            I2cTrans::write_read(0x74, vec![0x00], vec![lo, hi]),
+            I2cTrans::write_read(0x74, vec![0x00], vec![lo, hi]),
+        ];
+        let mut driver = Tps55288::new(I2cMock::new(&expectations));
+        driver
+            .set_feedback(FeedbackSource::Internal, ratio)
+            .unwrap();
+        driver.set_vout_mv(3_000).unwrap();
+        assert_eq!(driver.get_vout_mv().unwrap(), 3_000);
+        assert_eq!(driver.get_vout_mv_with_ratio(ratio).unwrap(), 3_000);
+        driver.free().done();
+    }
+}
+
+#[test]
+fn fault_mask_config_round_trip() {
+    use tps55288::data_types::FaultMaskConfig;
+
+    let masks = FaultMaskConfig {
+        short_circuit: false,
+        over_current: true,
+        over_voltage: false,
+    };
+    let expectations = [
+        // CDC_OPT and level 0b011 survive.
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b1110_1011]),
+        I2cTrans::write(0x74, vec![0x05, 0b0100_1011]),
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b0100_1011]),
+        I2cTrans::write_read(0x74, vec![0x05], vec![0b1110_1011]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.set_fault_mask_config(masks).unwrap();
+    assert_eq!(driver.get_fault_mask_config().unwrap(), masks);
+    assert_eq!(
+        driver.get_fault_mask_config().unwrap(),
+        FaultMaskConfig::default()
+    );
+    driver.free().done();
+}
+
+#[test]
+fn register_access_errors_pass_through_unchanged() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data);
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x02, 0x80]).with_error(ErrorKind::Bus),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x00]).with_error(nack),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]).with_error(ErrorKind::ArbitrationLoss),
+        I2cTrans::write_read(0x74, vec![0x00], vec![0x00; 2]).with_error(ErrorKind::Overrun),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    assert!(matches!(
+        driver.write_reg(0x02, 0x80),
+        Err(Error::I2c(ErrorKind::Bus))
+    ));
+    assert!(matches!(driver.read_reg(0x06), Err(Error::I2c(e)) if e == nack));
+    assert!(matches!(
+        driver.write_regs(0x00, &[0xD2, 0x00]),
+        Err(Error::I2c(ErrorKind::ArbitrationLoss))
+    ));
+    let mut buf = [0u8; 2];
+    assert!(matches!(
+        driver.read_regs(0x00, &mut buf),
+        Err(Error::I2c(ErrorKind::Overrun))
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn read_regs_bounds_checked_against_register_map() {
+    let regs = vec![0x00, 0x00, 0xE4, 0x01, 0x03, 0xE0, 0x20, 0x03];
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x00], regs.clone()),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x03, 0xFF]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let mut buf = [0u8; 8];
+    driver.read_regs(0x00, &mut buf).unwrap();
+    assert_eq!(buf.to_vec(), regs);
+    let mut past = [0u8; 2];
+    assert!(matches!(
+        driver.read_regs(0x07, &mut past),
+        Err(Error::OutOfRange)
+    ));
+    assert!(matches!(
+        driver.read_regs(0x00, &mut [0u8; 9]),
+        Err(Error::OutOfRange)
+    ));
+    driver.read_regs_unchecked(0x07, &mut past).unwrap();
+    assert_eq!(past, [0x03, 0xFF]);
+    driver.free().done();
+}
+
+#[test]
+fn set_vout_code_checks_range_for_feedback_mode() {
+    let expectations = [
+        // External feedback: full scale is accepted.
+        I2cTrans::write(0x74, vec![0x04, 0x80]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xFF, 0x03]),
+        // Internal 0.2256: REF=0000h is 0.2 V, below the 0.8 V minimum; 0078h is 0.8 V.
+        I2cTrans::write(0x74, vec![0x04, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x78, 0x00]),
+        // The unchecked variant writes regardless.
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x00, 0x00]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver
+        .set_feedback(FeedbackSource::External, InternalFeedbackRatio::R0_0564)
+        .unwrap();
+    driver.set_vout_code(0x03FF).unwrap();
+    driver
+        .set_feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_2256)
+        .unwrap();
+    assert!(matches!(
+        driver.set_vout_code(0x0000),
+        Err(Error::OutOfRange)
+    ));
+    driver.set_vout_code(0x0078).unwrap();
+    driver.set_vout_code_unchecked(0x0000).unwrap();
+    driver.free().done();
+}
+
+#[test]
+fn read_status_full_returns_raw_byte() {
+    // SCP | OVP | reserved bit 3 | buck-boost
+    let raw = 0b1010_1010;
+    let expectations = [I2cTrans::write_read(0x74, vec![0x07], vec![raw])];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let (operating, faults, byte) = driver.read_status_full().unwrap();
+    assert_eq!(byte, raw);
+    assert_eq!(operating, OperatingStatus::BuckBoost);
+    assert!(faults.short_circuit && !faults.over_current && faults.over_voltage);
+    assert_eq!(operating, OperatingStatus::from(byte));
+    assert_eq!(
+        faults,
+        FaultStatus::from_bits(StatusBits::from_bits_truncate(byte))
+    );
+    driver.free().done();
+}
+
+#[test]
+fn external_feedback_writes_zero_intfb() {
+    let expectations = [
+        I2cTrans::write(0x74, vec![0x04, 0x80]),
+        I2cTrans::write(0x74, vec![0x04, 0x80]),
+        I2cTrans::write(0x74, vec![0x04, 0x02]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver
+        .set_feedback(FeedbackSource::External, InternalFeedbackRatio::R0_1128)
+        .unwrap();
+    driver.set_external_feedback().unwrap();
+    // The cached ratio is untouched by the external selection.
+    assert_eq!(driver.feedback_ratio(), InternalFeedbackRatio::R0_1128);
+    driver
+        .set_internal_feedback(InternalFeedbackRatio::R0_0752)
+        .unwrap();
+    assert_eq!(driver.feedback_ratio(), InternalFeedbackRatio::R0_0752);
+    driver.free().done();
+}
+
+#[test]
+fn switch_feedback_drops_oe_around_vout_fs_write() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+        // OE off before VOUT_FS changes.
+        I2cTrans::write(0x74, vec![0x06, 0x20]),
+        I2cTrans::write(0x74, vec![0x04, 0x80]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x00, 0x02]),
+        // OE back on after the settle delay.
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+    ];
+    let log = EventLog::default();
+    let bus = LoggingI2c {
+        inner: I2cMock::new(&expectations),
+        log: log.clone(),
+    };
+    let mut delay = LoggingDelay { log: log.clone() };
+    let mut driver = Tps55288::new(bus);
+    driver
+        .switch_feedback(
+            FeedbackSource::External,
+            InternalFeedbackRatio::R0_0564,
+            0x0200,
+            500,
+            &mut delay,
+        )
+        .unwrap();
+    assert_eq!(
+        *log.borrow(),
+        ["i2c", "i2c", "i2c", "i2c", "i2c", "delay", "i2c"]
+    );
+    driver.free().inner.done();
+}
+
+#[test]
+fn stable_stepper_dwells_inside_crossover_window() {
+    use tps55288::StableStepper;
+
+    struct RecordingDelay(Vec<u32>);
+
+    impl embedded_hal::delay::DelayNs for RecordingDelay {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0.push(ns / 1_000);
+        }
+    }
+
+    // 4.0 V -> 6.0 V in 500 mV steps around a 5.0 V ± 500 mV crossover window.
+    let expectations: Vec<_> = [160u16, 185, 210, 235, 260]
+        .iter()
+        .flat_map(|code| {
+            let [lo, hi] = code.to_le_bytes();
+            [
+                I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+                I2cTrans::write(0x74, vec![0x00, lo, hi]),
+            ]
+        })
+        .collect();
+    let stepper = StableStepper::new(5_000, 500)
+        .step(500, 100)
+        .dwell_us(2_000);
+    assert!(stepper.in_window(4_500) && stepper.in_window(5_500));
+    assert!(!stepper.in_window(4_499) && !stepper.in_window(5_501));
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let mut delay = RecordingDelay(Vec::new());
+    stepper.run(&mut driver, 4_000, 6_000, &mut delay).unwrap();
+    assert_eq!(delay.0, [100, 2_000, 2_000, 2_000]);
+    driver.free().done();
+}
+
+#[test]
+fn stable_stepper_dwells_when_step_exceeds_window() {
+    use tps55288::StableStepper;
+
+    struct RecordingDelay(Vec<u32>);
+
+    impl embedded_hal::delay::DelayNs for RecordingDelay {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0.push(ns / 1_000);
+        }
+    }
+
+    fn ref_writes(codes: &[u16]) -> Vec<I2cTrans> {
+        codes
+            .iter()
+            .flat_map(|code| {
+                let [lo, hi] = code.to_le_bytes();
+                [
+                    I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+                    I2cTrans::write(0x74, vec![0x00, lo, hi]),
+                ]
+            })
+            .collect()
+    }
+
+    // 5.0 V ± 40 mV window: 4.95 V + 100 mV would skip past 5.04 V, so the step stops at 4.96 V.
+    let stepper = StableStepper::new(5_000, 40).step(100, 100).dwell_us(2_000);
+    assert_eq!(stepper.next_mv(4_950, 5_140), 4_960);
+    let expectations = ref_writes(&[207, 208, 213, 217]);
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let mut delay = RecordingDelay(Vec::new());
+    stepper.run(&mut driver, 4_950, 5_140, &mut delay).unwrap();
+    assert_eq!(delay.0, [100, 2_000, 100]);
+    driver.free().done();
+
+    // Falling with 200 mV steps stops at the upper edge (5.04 V).
+    let stepper = stepper.step(200, 100);
+    let expectations = ref_writes(&[215, 212, 202, 200]);
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    let mut delay = RecordingDelay(Vec::new());
+    stepper.run(&mut driver, 5_100, 4_800, &mut delay).unwrap();
+    assert_eq!(delay.0, [100, 2_000, 100]);
+    driver.free().done();
+}
+
+#[test]
+fn retry_policy_retries_failed_status_read() {
+    use embedded_hal::i2c::ErrorKind;
+    use tps55288::data_types::RetryPolicy;
+
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x00]).with_error(ErrorKind::Bus),
+        I2cTrans::write_read(0x74, vec![0x07], vec![0x41]),
+    ];
+    let mut driver =
+        Tps55288::new(I2cMock::new(&expectations)).with_retry_policy(RetryPolicy::new(3));
+    let (operating, faults) = driver.read_status().unwrap();
+    assert_eq!(operating, OperatingStatus::Buck);
+    assert!(faults.over_current);
+    driver.free().done();
+
+    // The default policy surfaces the first error.
+    let expectations =
+        [I2cTrans::write_read(0x74, vec![0x07], vec![0x00]).with_error(ErrorKind::Bus)];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    assert_eq!(driver.retry_policy(), RetryPolicy::default());
+    assert!(matches!(
+        driver.read_status(),
+        Err(Error::I2c(ErrorKind::Bus))
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn enable_output_verified_checks_oe_latched() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0xA0]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    driver.enable_output_verified().unwrap();
+    driver.free().done();
+
+    // Write acknowledged, but OE reads back clear.
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x74, vec![0x06, 0xA0]),
+        I2cTrans::write_read(0x74, vec![0x06], vec![0x20]),
+    ];
+    let mut driver = Tps55288::new(I2cMock::new(&expectations));
+    assert!(matches!(
+        driver.enable_output_verified(),
+        Err(Error::InvalidConfig)
+    ));
+    driver.free().done();
+}
+
+#[test]
+fn estimated_iin_covers_buck_and_boost() {
+    let mock = I2cMock::new(&[]);
+    let driver = Tps55288::new(mock);
+    // Buck 20 V -> 5 V at 3 A, 90 %: 15 W / 0.9 / 20 V = 833.3 mA, rounded up.
+    assert_eq!(driver.estimated_iin_ma(20_000, 5_000, 3_000, 90), 834);
+    // Boost 5 V -> 20 V at 3 A, 95 %: 60 W / 0.95 / 5 V = 12.63 A, above Iout.
+    let iin = driver.estimated_iin_ma(5_000, 20_000, 3_000, 95);
+    assert_eq!(iin, 12_632);
+    assert!(iin > 3_000);
+    // Lossless pass-through and degenerate inputs.
+    assert_eq!(driver.estimated_iin_ma(12_000, 12_000, 2_000, 100), 2_000);
+    assert_eq!(driver.estimated_iin_ma(12_000, 12_000, 2_000, 150), 2_000);
+    assert_eq!(driver.estimated_iin_ma(0, 5_000, 1_000, 90), u16::MAX);
+    assert_eq!(driver.estimated_iin_ma(1_500, 22_000, 6_000, 80), u16::MAX);
+    driver.free().done();
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "embassy")]
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+use embassy_sync::mutex::Mutex;
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use tps55288::data_types::{FeedbackSource, InternalFeedbackRatio, OperatingStatus};
+use tps55288::shared::SharedTps55288;
+
+/// The mock and an uncontended mutex complete immediately, so one poll is enough.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+#[test]
+fn shared_driver_locks_bus_per_operation() {
+    let expectations = [
+        I2cTrans::write(0x75, vec![0x04, 0x01]),
+        // 5 V on the cached 0.1128 ratio -> code 460.
+        I2cTrans::write_read(0x75, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x75, vec![0x00, 0xCC, 0x01]),
+        I2cTrans::write_read(0x75, vec![0x06], vec![0x20]),
+        I2cTrans::write(0x75, vec![0x06, 0xA0]),
+        I2cTrans::write_read(0x75, vec![0x07], vec![0x01]),
+    ];
+    let bus = Mutex::<NoopRawMutex, _>::new(I2cMock::new(&expectations));
+    let mut dev = SharedTps55288::with_address(&bus, 0x75);
+    block_on(async {
+        dev.with(async |d| {
+            d.set_feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_1128)
+                .await
+        })
+        .await
+        .unwrap();
+        // The bus is free again between operations.
+        assert!(bus.try_lock().is_ok());
+        dev.set_vout_mv(5_000).await.unwrap();
+        dev.enable_output().await.unwrap();
+        let (operating, faults) = dev.read_status().await.unwrap();
+        assert_eq!(operating, OperatingStatus::Buck);
+        assert!(faults.is_clear());
+    });
+    bus.into_inner().done();
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "serde")]
+
+use tps55288::Config;
+use tps55288::data_types::{
+    CableCompLevel, CableCompOption, FeedbackSource, InternalFeedbackRatio, LightLoadMode,
+    ModeConfig, OcpDelay, VoutSlewRate,
+};
+use tps55288::registers::ModeBits;
+
+#[test]
+fn config_roundtrips_through_json() {
+    let cfg = Config::new()
+        .vout_mv(12_000)
+        .ilim_ma(3_000, true)
+        .feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_1128)
+        .slew_rate(VoutSlewRate::Sr10MvPerUs)
+        .ocp_delay(OcpDelay::Ms6_144)
+        .cable_comp(CableCompOption::External, CableCompLevel::V0p5)
+        .fault_masks(true, false, true)
+        .light_load_mode(LightLoadMode::Pwm)
+        .enable_output(true);
+    let json = serde_json::to_string(&cfg).unwrap();
+    let back: Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, cfg);
+}
+
+#[test]
+fn mode_config_roundtrips_through_json() {
+    let mode = ModeConfig::from_bits(ModeBits::from_bits_truncate(0xAF));
+    let json = serde_json::to_string(&mode).unwrap();
+    let back: ModeConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, mode);
+}
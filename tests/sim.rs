@@ -0,0 +1,56 @@
+#![cfg(all(feature = "sim", not(feature = "async")))]
+
+use tps55288::Tps55288;
+use tps55288::data_types::{FeedbackSource, InternalFeedbackRatio, OperatingStatus};
+use tps55288::registers::{RESET_MODE, StatusBits, addr};
+use tps55288::sim::MemI2c;
+
+#[test]
+fn vout_round_trips_through_memory_bus() {
+    let mut dev = Tps55288::new(MemI2c::new());
+    assert_eq!(dev.get_vout_mv().unwrap(), 5_000);
+    dev.set_vout_mv(12_000).unwrap();
+    assert_eq!(dev.get_vout_mv().unwrap(), 12_000);
+    dev.set_feedback(FeedbackSource::Internal, InternalFeedbackRatio::R0_1128)
+        .unwrap();
+    dev.set_vout_mv(7_120).unwrap();
+    assert_eq!(
+        dev.get_feedback().unwrap().1,
+        InternalFeedbackRatio::R0_1128
+    );
+    assert_eq!(dev.free().registers()[..2], [0xA0, 0x02]);
+}
+
+#[test]
+fn status_faults_clear_on_read() {
+    let mut bus = MemI2c::new();
+    bus.set_register(addr::STATUS, (StatusBits::OCP | StatusBits::STATUS0).bits());
+    let mut dev = Tps55288::new(bus);
+    let (mode, faults) = dev.read_status().unwrap();
+    assert_eq!(mode, OperatingStatus::Buck);
+    assert!(faults.over_current);
+    assert!(dev.read_fault_status().unwrap().is_clear());
+}
+
+#[test]
+fn wrong_address_nacks() {
+    let mut dev = Tps55288::with_address(MemI2c::new(), 0x75);
+    assert!(!dev.probe().unwrap());
+    dev.select_default_address();
+    dev.enable_output().unwrap();
+    assert!(dev.is_output_enabled().unwrap());
+    assert_eq!(
+        dev.free().registers()[addr::MODE as usize],
+        RESET_MODE | 0x80
+    );
+}
+
+#[test]
+fn is_at_reset_detects_power_on_state() {
+    let mut dev = Tps55288::new(MemI2c::new());
+    assert!(dev.is_at_reset().unwrap());
+    dev.set_vout_mv(12_000).unwrap();
+    assert!(!dev.is_at_reset().unwrap());
+    dev.reset_to_defaults().unwrap();
+    assert!(dev.is_at_reset().unwrap());
+}
@@ -40,6 +40,35 @@ fn vout_sr_bits_mapping() {
     ];
 }
 
+#[test]
+fn vout_sr_never_sets_reserved_bits() {
+    use tps55288::registers::{RESERVED_MASKS, VoutSrConfig, addr};
+
+    let reserved = RESERVED_MASKS[addr::VOUT_SR as usize];
+    let slews = [
+        VoutSlewRate::Sr1p25MvPerUs,
+        VoutSlewRate::Sr2p5MvPerUs,
+        VoutSlewRate::Sr5MvPerUs,
+        VoutSlewRate::Sr10MvPerUs,
+    ];
+    let delays = [
+        OcpDelay::Us128,
+        OcpDelay::Ms3_072,
+        OcpDelay::Ms6_144,
+        OcpDelay::Ms12_288,
+    ];
+    for slew in slews {
+        for delay in delays {
+            let bits = VoutSrConfig::new().slew(slew).ocp_delay(delay).build();
+            let raw = bits.bits();
+            assert_eq!(raw & reserved, 0);
+            assert_eq!(bits.sanitize().bits(), raw);
+        }
+    }
+    let raw = VoutSrBits::from_bits_retain(0xFF);
+    assert_eq!(raw.sanitize().bits(), 0b0011_0011);
+}
+
 #[test]
 fn cdc_level_bits_mapping() {
     let levels = [
@@ -85,3 +114,294 @@ fn conversion_edges_match_constants() {
         tps55288::registers::VOUT_MAX_MV
     );
 }
+
+#[test]
+fn mode_config_roundtrips_every_byte() {
+    use tps55288::data_types::ModeConfig;
+    use tps55288::registers::ModeBits;
+
+    for raw in 0..=u8::MAX {
+        let cfg = ModeConfig::from_bits(ModeBits::from_bits_truncate(raw));
+        assert_eq!(cfg.to_bits().bits(), raw);
+    }
+    // Reset value: hiccup only.
+    assert_eq!(ModeConfig::default().to_bits().bits(), 0b0010_0000);
+}
+
+#[test]
+fn operating_status_from_every_two_bit_value() {
+    use tps55288::data_types::OperatingStatus;
+    use tps55288::registers::decode_status;
+
+    let expected = [
+        (0b00, OperatingStatus::Boost),
+        (0b01, OperatingStatus::Buck),
+        (0b10, OperatingStatus::BuckBoost),
+        (0b11, OperatingStatus::Reserved),
+    ];
+    for (raw, status) in expected {
+        assert_eq!(OperatingStatus::from(raw), status);
+        // Fault and reserved bits do not affect the decoded mode.
+        assert_eq!(OperatingStatus::from(raw | 0xE0), status);
+        assert_eq!(
+            decode_status(&StatusBits::from_bits_truncate(raw | 0xE0)),
+            status
+        );
+    }
+}
+
+#[test]
+fn operating_status_predicates_match_exactly_one_variant() {
+    use tps55288::data_types::OperatingStatus;
+
+    let cases = [
+        (OperatingStatus::Boost, [true, false, false, false]),
+        (OperatingStatus::Buck, [false, true, false, false]),
+        (OperatingStatus::BuckBoost, [false, false, true, false]),
+        (OperatingStatus::Reserved, [false, false, false, true]),
+    ];
+    for (status, expected) in cases {
+        let actual = [
+            status.is_boost(),
+            status.is_buck(),
+            status.is_buck_boost(),
+            status.is_transitioning(),
+        ];
+        assert_eq!(actual, expected, "{status:?}");
+    }
+}
+
+#[test]
+fn fault_status_predicates_cover_all_combinations() {
+    use tps55288::data_types::{Fault, FaultStatus};
+
+    for raw in 0u8..8 {
+        let faults = FaultStatus {
+            short_circuit: raw & 0b001 != 0,
+            over_current: raw & 0b010 != 0,
+            over_voltage: raw & 0b100 != 0,
+        };
+        assert_eq!(faults.any(), raw != 0);
+        assert_eq!(faults.is_clear(), raw == 0);
+
+        let mut active = faults.active();
+        if faults.short_circuit {
+            assert_eq!(active.next(), Some(Fault::ShortCircuit));
+        }
+        if faults.over_current {
+            assert_eq!(active.next(), Some(Fault::OverCurrent));
+        }
+        if faults.over_voltage {
+            assert_eq!(active.next(), Some(Fault::OverVoltage));
+        }
+        assert_eq!(active.next(), None);
+        assert_eq!(faults.active().count(), raw.count_ones() as usize);
+    }
+}
+
+#[test]
+fn mode_preset_lookup() {
+    use tps55288::data_types::{
+        I2cAddress, LightLoadMode, MODE_PRESETS, VccSource, preset_for_resistor,
+        preset_index_for_config,
+    };
+
+    let preset = preset_for_resistor(6.19, 0.01).unwrap();
+    assert_eq!(preset.light_load_mode, LightLoadMode::Pfm);
+    assert_eq!(preset.address, I2cAddress::Addr0x74);
+    assert_eq!(preset.vcc_source, VccSource::Internal);
+    // 6.19 kΩ measured as 6.25 kΩ is inside 2 % but not 0.5 %.
+    assert_eq!(preset_for_resistor(6.25, 0.02), Some(&MODE_PRESETS[1]));
+    assert_eq!(preset_for_resistor(6.25, 0.005), None);
+    // Between 51.1 and 75 kΩ with a tight tolerance: no match.
+    assert_eq!(preset_for_resistor(62.0, 0.05), None);
+    assert_eq!(preset_for_resistor(0.0, 0.01), Some(&MODE_PRESETS[0]));
+    assert_eq!(
+        preset_for_resistor(f32::INFINITY, 0.01),
+        Some(&MODE_PRESETS[7])
+    );
+
+    assert_eq!(
+        preset_index_for_config(
+            VccSource::Internal,
+            I2cAddress::Addr0x74,
+            LightLoadMode::Pfm
+        ),
+        Some(1)
+    );
+    assert_eq!(
+        preset_index_for_config(
+            VccSource::External5v,
+            I2cAddress::Addr0x75,
+            LightLoadMode::Pwm
+        ),
+        Some(6)
+    );
+    for (i, p) in MODE_PRESETS.iter().enumerate() {
+        assert_eq!(
+            preset_index_for_config(p.vcc_source, p.address, p.light_load_mode),
+            Some(i)
+        );
+    }
+}
+
+#[test]
+fn preset_addresses() {
+    use tps55288::data_types::{MODE_PRESETS, address_for_preset};
+
+    let expected = [0x74, 0x74, 0x75, 0x75, 0x74, 0x74, 0x75, 0x75];
+    for (preset, addr) in MODE_PRESETS.iter().zip(expected) {
+        assert_eq!(address_for_preset(preset), addr);
+    }
+}
+
+#[test]
+fn unit_newtypes_convert() {
+    use tps55288::data_types::{Milliamps, Millivolts};
+
+    assert_eq!(u16::from(Millivolts(5_000)), 5_000);
+    assert_eq!(u16::from(Milliamps(3_000)), 3_000);
+    assert!(Millivolts(5_000) < Millivolts(9_000));
+}
+
+#[test]
+fn status_report_from_known_registers() {
+    use tps55288::data_types::{
+        LightLoadMode, LightLoadOverride, OperatingStatus, StatusReport, VccSource,
+    };
+
+    // MODE: OE | HICCUP | MODE | PFM(=FPWM); STATUS: OCP latched, buck.
+    let report = StatusReport::from_registers(0b1010_0011, 0b0100_0001, 0x74);
+    assert_eq!(report.operating, OperatingStatus::Buck);
+    assert!(report.faults.over_current);
+    assert!(report.output_enabled);
+    assert_eq!(report.light_load_mode, LightLoadMode::Pwm);
+    assert_eq!(report.light_load_override, LightLoadOverride::FromRegister);
+    assert_eq!(report.vcc_source, VccSource::Internal);
+    assert_eq!(report.address, 0x74);
+}
+
+#[test]
+fn fault_summary_and_operating_labels() {
+    use tps55288::data_types::{FaultStatus, OperatingStatus};
+
+    let cases = [
+        (0x00, "OK"),
+        (0x80, "SCP"),
+        (0x40, "OCP"),
+        (0x60, "OCP+OVP"),
+        (0xA0, "SCP+OVP"),
+        (0xE0, "SCP+OCP+OVP"),
+    ];
+    for (raw, summary) in cases {
+        let faults = FaultStatus::from_bits(StatusBits::from_bits_truncate(raw));
+        assert_eq!(faults.summary(), summary);
+    }
+    assert_eq!(OperatingStatus::Boost.as_str(), "Boost");
+    assert_eq!(OperatingStatus::Buck.as_str(), "Buck");
+    assert_eq!(OperatingStatus::BuckBoost.as_str(), "BuckBoost");
+    assert_eq!(OperatingStatus::Reserved.as_str(), "Reserved");
+}
+
+#[test]
+fn retry_policy_filters_error_kinds() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+    use tps55288::data_types::RetryPolicy;
+
+    let policy = RetryPolicy {
+        max_attempts: 2,
+        retry_arbitration_loss: true,
+        retry_bus_errors: false,
+    };
+    assert!(policy.should_retry(1, ErrorKind::ArbitrationLoss));
+    assert!(!policy.should_retry(2, ErrorKind::ArbitrationLoss));
+    assert!(!policy.should_retry(1, ErrorKind::Bus));
+    let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+    assert!(!RetryPolicy::new(5).should_retry(1, nack));
+    assert!(RetryPolicy::new(5).should_retry(4, ErrorKind::Other));
+    assert!(!RetryPolicy::default().should_retry(1, ErrorKind::Bus));
+}
+
+/// Fixed-capacity `core::fmt::Write` sink, as a no_std logger would use.
+struct FmtBuf {
+    buf: [u8; 32],
+    len: usize,
+}
+
+impl FmtBuf {
+    fn format(value: &dyn core::fmt::Display) -> Self {
+        use core::fmt::Write;
+        let mut out = FmtBuf {
+            buf: [0; 32],
+            len: 0,
+        };
+        write!(out, "{}", value).unwrap();
+        out
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+}
+
+impl core::fmt::Write for FmtBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn display_config_enums() {
+    use tps55288::data_types::{
+        FeedbackSource, I2cAddress, InternalFeedbackRatio, LightLoadMode, LightLoadOverride,
+        OperatingStatus, VccSource,
+    };
+
+    let cases: [(&dyn core::fmt::Display, &str); 36] = [
+        (&I2cAddress::Addr0x74, "0x74"),
+        (&I2cAddress::Addr0x75, "0x75"),
+        (&LightLoadMode::Pfm, "PFM"),
+        (&LightLoadMode::Pwm, "FPWM"),
+        (&VccSource::Internal, "internal LDO"),
+        (&VccSource::External5v, "external 5 V"),
+        (&LightLoadOverride::FromPreset, "MODE pin"),
+        (&LightLoadOverride::FromRegister, "MODE register"),
+        (&VoutSlewRate::Sr1p25MvPerUs, "1.25 mV/µs"),
+        (&VoutSlewRate::Sr2p5MvPerUs, "2.5 mV/µs"),
+        (&VoutSlewRate::Sr5MvPerUs, "5 mV/µs"),
+        (&VoutSlewRate::Sr10MvPerUs, "10 mV/µs"),
+        (&OcpDelay::Us128, "128 µs"),
+        (&OcpDelay::Ms3_072, "3.072 ms"),
+        (&OcpDelay::Ms6_144, "6.144 ms"),
+        (&OcpDelay::Ms12_288, "12.288 ms"),
+        (&FeedbackSource::Internal, "internal"),
+        (&FeedbackSource::External, "external"),
+        (&InternalFeedbackRatio::R0_2256, "0.2256"),
+        (&InternalFeedbackRatio::R0_1128, "0.1128"),
+        (&InternalFeedbackRatio::R0_0752, "0.0752"),
+        (&InternalFeedbackRatio::R0_0564, "0.0564"),
+        (&CableCompOption::Internal, "internal"),
+        (&CableCompOption::External, "external"),
+        (&CableCompLevel::V0p0, "0 V"),
+        (&CableCompLevel::V0p1, "0.1 V"),
+        (&CableCompLevel::V0p2, "0.2 V"),
+        (&CableCompLevel::V0p3, "0.3 V"),
+        (&CableCompLevel::V0p4, "0.4 V"),
+        (&CableCompLevel::V0p5, "0.5 V"),
+        (&CableCompLevel::V0p6, "0.6 V"),
+        (&CableCompLevel::V0p7, "0.7 V"),
+        (&OperatingStatus::Boost, "Boost"),
+        (&OperatingStatus::Buck, "Buck"),
+        (&OperatingStatus::BuckBoost, "BuckBoost"),
+        (&OperatingStatus::Reserved, "Reserved"),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(FmtBuf::format(value).as_str(), expected);
+    }
+}
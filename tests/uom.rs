@@ -0,0 +1,43 @@
+#![cfg(all(feature = "uom", not(feature = "async")))]
+
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+use tps55288::data_types::{Milliamps, Millivolts};
+use tps55288::driver::Tps55288;
+use uom::si::electric_current::ampere;
+use uom::si::electric_potential::volt;
+use uom::si::f32::{ElectricCurrent, ElectricPotential};
+
+#[test]
+fn uom_quantities_convert_to_units() {
+    assert_eq!(
+        Millivolts::from(ElectricPotential::new::<volt>(5.0)),
+        Millivolts(5_000)
+    );
+    assert_eq!(
+        Milliamps::from(ElectricCurrent::new::<ampere>(3.0)),
+        Milliamps(3_000)
+    );
+}
+
+#[test]
+fn set_vout_accepts_uom_quantities() {
+    let expectations = [
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0xD2, 0x00]),
+        I2cTrans::write_read(0x74, vec![0x01], vec![0x00]),
+        I2cTrans::write(0x74, vec![0x00, 0x30, 0x02]),
+        I2cTrans::write(0x74, vec![0x02, 0x80 | 60]),
+    ];
+    let mock = I2cMock::new(&expectations);
+    let mut driver = Tps55288::new(mock);
+    driver
+        .set_vout(ElectricPotential::new::<volt>(5.0))
+        .unwrap();
+    driver
+        .set_vout(ElectricPotential::new::<volt>(12.0))
+        .unwrap();
+    driver
+        .set_ilim(ElectricCurrent::new::<ampere>(3.0), true)
+        .unwrap();
+    driver.free().done();
+}